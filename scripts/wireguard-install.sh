@@ -239,16 +239,23 @@ if [[ ! -e /etc/wireguard/wg0.conf ]]; then
 		ip6=$(ip -6 addr | grep 'inet6 [23]' | cut -d '/' -f 1 | grep -oE '([0-9a-fA-F]{0,4}:){1,7}[0-9a-fA-F]{0,4}' | sed -n "$ip6_number"p)
 	fi
 	echo
-	echo "What port should WireGuard listen to?"
-	read -p "Port [51820]: " port
+	# WG_PORT and WG_CLIENT answer the next two questions, e.g. when set by linuxtoolbox
+	port="$WG_PORT"
+	if [[ -z "$port" ]]; then
+		echo "What port should WireGuard listen to?"
+		read -p "Port [51820]: " port
+	fi
 	until [[ -z "$port" || "$port" =~ ^[0-9]+$ && "$port" -le 65535 ]]; do
 		echo "$port: invalid port."
 		read -p "Port [51820]: " port
 	done
 	[[ -z "$port" ]] && port="51820"
 	echo
-	echo "Enter a name for the first client:"
-	read -p "Name [client]: " unsanitized_client
+	unsanitized_client="$WG_CLIENT"
+	if [[ -z "$unsanitized_client" ]]; then
+		echo "Enter a name for the first client:"
+		read -p "Name [client]: " unsanitized_client
+	fi
 	# Allow a limited lenght and set of characters to avoid conflicts
 	client=$(sed 's/[^0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ_-]/_/g' <<< "$unsanitized_client" | cut -c-15)
 	[[ -z "$client" ]] && client="client"
@@ -0,0 +1,821 @@
+use std::collections::{BTreeMap, HashMap};
+use std::io::{BufRead, ErrorKind, IsTerminal, Write};
+use std::os::unix::process::ExitStatusExt;
+use std::path::PathBuf;
+
+use chrono::Local;
+use serde_json::json;
+
+use crate::config::{CatalogLayer, CatalogSource};
+use crate::doctor::{self, Severity};
+use crate::integrity::{self, Integrity, LockEntry, LockFile};
+use crate::{
+    execute_and_record, find_program_by_key, record_run, get_system_info, load_catalog, logging, Category, Profile, Program,
+    RiskLevel, CURRENT_VERSION,
+};
+use crate::{keymap, params, privilege, search, settings, update};
+
+pub const EXIT_OK: i32 = 0;
+pub const EXIT_FAILURE: i32 = 1;
+pub const EXIT_USAGE: i32 = 2;
+pub const EXIT_UNKNOWN_PROGRAM: i32 = 3;
+pub const EXIT_NO_MATCHES: i32 = 4;
+pub const EXIT_INTEGRITY: i32 = 5;
+// Same codes bash uses for "not executable" and "command not found"
+pub const EXIT_SCRIPT_NOT_EXECUTABLE: i32 = 126;
+pub const EXIT_SCRIPT_NOT_FOUND: i32 = 127;
+
+pub const USAGE: &str = "\
+Usage: linuxtoolbox [OPTIONS] [COMMAND]
+
+Run without a command to start the interactive menu.
+
+Options:
+  --config <FILE>               Load this catalog on top of the discovered ones
+  --log-level <LEVEL>           error, warn, info, debug or trace (default: $LINUXTOOLBOX_LOG or info)
+  -v, --verbose                 Same as --log-level debug
+  --offline                     Never contact the release server (also $LINUXTOOLBOX_OFFLINE)
+
+Commands:
+  list [--json]                 List every category and program
+  run <Category>/<Program>      Run a program's script (a unique program name also works)
+      [name=value...]           Set the program's parameters; others use remembered values or defaults
+      [--allow-modified]        Run even if the script no longer matches its pinned sha256
+      [--yes]                   Run a destructive program without typing its name to confirm
+  profiles [--json]             List the profiles defined in [profiles] and their programs
+  profile <name>                Run every program of a profile in order, stopping at the first failure
+      [--keep-going]            Run the rest even after a program fails
+      [--allow-modified] [--yes]  As for run
+  search <query> [--json]       List programs matching the query by name, category, description, tag or alias
+      [--contents]              Search the scripts and the files they source instead, showing matching lines
+  pin [<Category>/<Program>...]  Record checksums of scripts and the files they source in linuxtoolbox.lock next to each config.toml
+  doctor [--fix] [--json]       Check every script, --fix applies safe fixes such as chmod +x
+  update [--check]              Install the latest release (--check only reports it)
+      [--skip-checksum]         Install even if the release publishes no sha256
+  info                          Print system information
+  help                          Show this message
+  version                       Print the version
+
+Exit codes:
+  0 success, 1 failure (or problems found by doctor), 2 usage error (or an invalid parameter),
+  3 unknown program or profile,
+  4 no search matches,
+  5 script does not match its pinned checksum,
+  126 script not executable, 127 script not found,
+  otherwise the exit code of the script that was run
+
+Catalogs are merged in this order, later ones overriding programs of the same name:
+  config.toml next to the binary, /etc/linuxtoolbox/config.toml,
+  $XDG_CONFIG_HOME/linuxtoolbox/config.toml, ./config.toml, --config <FILE>.
+The built-in catalog is used when none of these exist.";
+
+/// Options accepted before or after any command.
+pub struct GlobalOptions {
+    pub log_level: Option<logging::Level>,
+    pub config: Option<PathBuf>,
+    pub offline: bool,
+}
+
+/// Removes the global options from `args`, leaving the command and its own arguments.
+pub fn take_global_options(args: &mut Vec<String>) -> Result<GlobalOptions, String> {
+    let mut options = GlobalOptions { log_level: None, config: None, offline: false };
+    let mut remaining = Vec::with_capacity(args.len());
+    let mut iter = std::mem::take(args).into_iter();
+
+    while let Some(arg) = iter.next() {
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag.to_string(), Some(value.to_string())),
+            _ => (arg.clone(), None),
+        };
+        match flag.as_str() {
+            "--log-level" => {
+                let value = inline_value
+                    .or_else(|| iter.next())
+                    .ok_or("--log-level expects a level")?;
+                options.log_level = Some(
+                    logging::Level::parse(&value).ok_or_else(|| format!("Unknown log level: {}", value))?,
+                );
+            }
+            "--config" => {
+                let value = inline_value
+                    .or_else(|| iter.next())
+                    .ok_or("--config expects a file")?;
+                options.config = Some(PathBuf::from(value));
+            }
+            "--offline" => options.offline = true,
+            "-v" | "--verbose" => options.log_level = Some(logging::Level::Debug),
+            _ => remaining.push(arg),
+        }
+    }
+
+    *args = remaining;
+    Ok(options)
+}
+
+pub enum CliCommand {
+    List { json: bool },
+    Run { target: String, params: HashMap<String, String>, allow_modified: bool, yes: bool },
+    Profiles { json: bool },
+    Profile { name: String, keep_going: bool, allow_modified: bool, yes: bool },
+    Pin { targets: Vec<String> },
+    Search { query: String, contents: bool, json: bool },
+    Doctor { fix: bool, json: bool },
+    Update { check_only: bool, skip_checksum: bool },
+    Info,
+    Help,
+    Version,
+}
+
+/// Parses the process arguments. `Ok(None)` means no command was given and the TUI should start.
+pub fn parse_args(args: &[String]) -> Result<Option<CliCommand>, String> {
+    let Some((command, rest)) = args.split_first() else {
+        return Ok(None);
+    };
+
+    let known_flags: &[&str] = match command.as_str() {
+        "doctor" => &["--json", "--fix"],
+        "run" => &["--allow-modified", "--yes"],
+        "profile" => &["--keep-going", "--allow-modified", "--yes"],
+        "update" => &["--check", "--skip-checksum"],
+        "search" => &["--json", "--contents"],
+        "list" | "profiles" => &["--json"],
+        _ => &[],
+    };
+    let json = rest.iter().any(|a| a == "--json");
+    let fix = rest.iter().any(|a| a == "--fix");
+    let positional: Vec<&String> = rest.iter().filter(|a| !a.starts_with("--")).collect();
+    if let Some(flag) = rest.iter().find(|a| a.starts_with("--") && !known_flags.contains(&a.as_str())) {
+        return Err(format!("Unknown option: {}", flag));
+    }
+
+    let command = match command.as_str() {
+        "list" => {
+            if !positional.is_empty() {
+                return Err("list takes no arguments".to_string());
+            }
+            CliCommand::List { json }
+        }
+        "run" => match positional.split_first() {
+            Some((target, assignments)) => {
+                let mut params = HashMap::new();
+                for assignment in assignments {
+                    let Some((name, value)) = assignment.split_once('=') else {
+                        return Err(format!("run expects one <Category>/<Program> and name=value parameters, got '{}'", assignment));
+                    };
+                    params.insert(name.to_string(), value.to_string());
+                }
+                CliCommand::Run {
+                    target: target.to_string(),
+                    params,
+                    allow_modified: rest.iter().any(|a| a == "--allow-modified"),
+                    yes: rest.iter().any(|a| a == "--yes"),
+                }
+            }
+            None => return Err("run expects a <Category>/<Program> argument".to_string()),
+        },
+        "profiles" => {
+            if !positional.is_empty() {
+                return Err("profiles takes no arguments".to_string());
+            }
+            CliCommand::Profiles { json }
+        }
+        "profile" => match positional.as_slice() {
+            [name] => CliCommand::Profile {
+                name: name.to_string(),
+                keep_going: rest.iter().any(|a| a == "--keep-going"),
+                allow_modified: rest.iter().any(|a| a == "--allow-modified"),
+                yes: rest.iter().any(|a| a == "--yes"),
+            },
+            _ => return Err("profile expects exactly one profile name".to_string()),
+        },
+        "search" => {
+            if positional.is_empty() {
+                return Err("search expects a query".to_string());
+            }
+            let query = positional.iter().map(|s| s.as_str()).collect::<Vec<_>>().join(" ");
+            CliCommand::Search { query, contents: rest.iter().any(|a| a == "--contents"), json }
+        }
+        "pin" => CliCommand::Pin { targets: positional.iter().map(|s| s.to_string()).collect() },
+        "doctor" => {
+            if !positional.is_empty() {
+                return Err("doctor takes no arguments".to_string());
+            }
+            CliCommand::Doctor { fix, json }
+        }
+        "update" => {
+            if !positional.is_empty() {
+                return Err("update takes no arguments".to_string());
+            }
+            CliCommand::Update {
+                check_only: rest.iter().any(|a| a == "--check"),
+                skip_checksum: rest.iter().any(|a| a == "--skip-checksum"),
+            }
+        }
+        "info" => CliCommand::Info,
+        "help" | "--help" | "-h" => CliCommand::Help,
+        "version" | "--version" | "-V" => CliCommand::Version,
+        other => return Err(format!("Unknown command: {}", other)),
+    };
+
+    Ok(Some(command))
+}
+
+/// Runs a CLI command and returns the process exit code.
+pub fn run(command: CliCommand, layers: &[CatalogLayer], update_source: &update::UpdateSource, sudo: &settings::SudoSettings) -> i32 {
+    match command {
+        CliCommand::Help => {
+            println!("{}", USAGE);
+            EXIT_OK
+        }
+        CliCommand::Version => {
+            println!("linuxtoolbox {}", CURRENT_VERSION);
+            EXIT_OK
+        }
+        CliCommand::Info => {
+            println!("Linux Toolbox v{}", CURRENT_VERSION);
+            println!("{}", get_system_info());
+            println!("Log file: {}", logging::log_file().display());
+            println!("Settings: {}", settings::settings_path().display());
+            println!("Key bindings: {}", keymap::keymap_path().display());
+            println!("Releases: {}", update_source.repo);
+            for layer in layers {
+                println!("Catalog ({}): {}", layer.source.display_name(), layer.path.display());
+            }
+            EXIT_OK
+        }
+        CliCommand::List { json } => with_catalog(layers, |categories| {
+            list(categories, json);
+            EXIT_OK
+        }),
+        CliCommand::Search { query, contents, json } => with_catalog(layers, |categories| {
+            search(categories, &query, contents, json)
+        }),
+        CliCommand::Run { target, params, allow_modified, yes } => with_catalog(layers, |categories| {
+            run_program(categories, &target, &params, allow_modified, yes, sudo)
+        }),
+        CliCommand::Profiles { json } => with_profiles(layers, |categories, profiles| {
+            list_profiles(categories, profiles, json);
+            EXIT_OK
+        }),
+        CliCommand::Profile { name, keep_going, allow_modified, yes } => with_profiles(layers, |categories, profiles| {
+            run_profile(categories, profiles, &name, keep_going, allow_modified, yes, sudo)
+        }),
+        CliCommand::Pin { targets } => with_catalog(layers, |categories| {
+            pin(categories, &targets)
+        }),
+        CliCommand::Update { check_only, skip_checksum } => run_update(update_source, check_only, skip_checksum),
+        CliCommand::Doctor { fix, json } => with_catalog(layers, |categories| {
+            run_doctor(categories, fix, json)
+        }),
+    }
+}
+
+fn with_catalog(layers: &[CatalogLayer], f: impl FnOnce(&[Category]) -> i32) -> i32 {
+    with_profiles(layers, |categories, _| f(categories))
+}
+
+fn with_profiles(layers: &[CatalogLayer], f: impl FnOnce(&[Category], &[Profile]) -> i32) -> i32 {
+    match load_catalog(layers) {
+        Ok((categories, profiles)) => f(&categories, &profiles),
+        Err(e) => {
+            eprintln!("Failed to load catalog: {}", e);
+            EXIT_FAILURE
+        }
+    }
+}
+
+fn program_json(category: &str, program: &Program) -> serde_json::Value {
+    json!({
+        "category": category,
+        "name": program.name,
+        "script": program.script,
+        "description": program.description,
+        "tags": program.tags,
+        "distros": program.distros,
+        "requires_root": program.requires_root,
+        "risk": program.risk,
+        "untested": program.untested,
+        "aliases": program.aliases,
+        "source": program.source.source.display_name(),
+        "source_file": program.source.path,
+    })
+}
+
+fn list(categories: &[Category], json: bool) {
+    if json {
+        let entries: Vec<_> = categories
+            .iter()
+            .flat_map(|c| c.programs.iter().map(move |p| program_json(&c.name, p)))
+            .collect();
+        println!("{}", serde_json::Value::Array(entries));
+        return;
+    }
+
+    for category in categories {
+        println!("{}", category.name);
+        for program in &category.programs {
+            let mut badges = String::new();
+            if program.risk == RiskLevel::Destructive {
+                badges.push_str("  [destructive]");
+            }
+            if program.untested {
+                badges.push_str("  [untested]");
+            }
+            println!("  {:<28} {}{}", program.name, program.script.display(), badges);
+            if let Some(description) = &program.description {
+                println!("  {:<28} {}", "", description);
+            }
+        }
+    }
+}
+
+fn search(categories: &[Category], query: &str, contents: bool, json: bool) -> i32 {
+    let matches = if contents { search::search_contents(categories, query) } else { search::search(categories, query) };
+
+    if json {
+        let entries: Vec<_> = matches
+            .iter()
+            .map(|result| {
+                let key = format!("{}/{}", result.category, result.name);
+                let script = find_program_by_key(categories, &key).map(|program| &program.script);
+                json!({
+                    "category": result.category,
+                    "name": result.name,
+                    "script": script,
+                    "score": result.score,
+                    "matched": result.context.as_ref().map(|context| json!({ "field": context.label, "text": context.text })),
+                    "lines": result.lines.iter().map(|line| json!({ "file": line.file, "line": line.number, "text": line.text })).collect::<Vec<_>>(),
+                })
+            })
+            .collect();
+        println!("{}", serde_json::Value::Array(entries));
+    } else {
+        for result in &matches {
+            match &result.context {
+                Some(context) => println!("{}/{}  ({}: {})", result.category, result.name, context.label, context.text),
+                None => println!("{}/{}", result.category, result.name),
+            }
+            for line in &result.lines {
+                match &line.file {
+                    Some(file) => println!("    {}:{}: {}", file, line.number, line.text),
+                    None => println!("    {}: {}", line.number, line.text),
+                }
+            }
+        }
+    }
+
+    if matches.is_empty() { EXIT_NO_MATCHES } else { EXIT_OK }
+}
+
+/// Resolves `Category/Program`, or a bare program name when it is unique. Names are case-insensitive.
+fn find_program<'a>(categories: &'a [Category], target: &str) -> Result<(&'a Category, &'a Program), String> {
+    let (category_name, program_name) = match target.split_once('/') {
+        Some((c, p)) => (Some(c), p),
+        None => (None, target),
+    };
+
+    let candidates: Vec<_> = categories
+        .iter()
+        .filter(|c| category_name.is_none_or(|name| c.name.eq_ignore_ascii_case(name)))
+        .flat_map(|c| c.programs.iter().map(move |p| (c, p)))
+        .filter(|(_, p)| p.name.eq_ignore_ascii_case(program_name))
+        .collect();
+
+    match candidates.as_slice() {
+        [found] => Ok(*found),
+        [] => Err(format!("No program named '{}'", target)),
+        many => Err(format!(
+            "'{}' is ambiguous, use one of: {}",
+            target,
+            many.iter().map(|(c, p)| format!("{}/{}", c.name, p.name)).collect::<Vec<_>>().join(", ")
+        )),
+    }
+}
+
+fn run_program(
+    categories: &[Category],
+    target: &str,
+    given: &HashMap<String, String>,
+    allow_modified: bool,
+    yes: bool,
+    sudo: &settings::SudoSettings,
+) -> i32 {
+    match find_program(categories, target) {
+        Ok((_, program)) => {
+            if !confirm_risks(&[program], yes) {
+                return EXIT_FAILURE;
+            }
+            let sudo_password = match prepare_privileges(sudo, &[program]) {
+                Ok(password) => password,
+                Err(code) => return code,
+            };
+            execute_program(program, given, allow_modified, sudo_password.as_ref())
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            EXIT_UNKNOWN_PROGRAM
+        }
+    }
+}
+
+/// Warns about untested programs and has the user type the name of each destructive one, unless
+/// `yes` was given. Returns false when the run should not go ahead.
+fn confirm_risks(programs: &[&Program], yes: bool) -> bool {
+    for program in programs {
+        if program.untested {
+            eprintln!("Warning: {} has not been tested yet", program.key());
+        }
+    }
+    for program in programs.iter().filter(|program| program.risk == RiskLevel::Destructive) {
+        eprintln!("{} is destructive: {}", program.key(), program.description.as_deref().unwrap_or("it can erase data or leave the system unusable"));
+        if yes {
+            continue;
+        }
+        if !std::io::stdin().is_terminal() {
+            eprintln!("Pass --yes to run it without a terminal to confirm on");
+            return false;
+        }
+        eprint!("Type {} to run it: ", program.name);
+        let _ = std::io::stderr().flush();
+        let mut typed = String::new();
+        if std::io::stdin().lock().read_line(&mut typed).is_err() || typed.trim() != program.name {
+            eprintln!("Cancelled");
+            return false;
+        }
+        logging::warn("Destructive program confirmed", json!({ "program": program.key() }));
+    }
+    true
+}
+
+/// Applies the `as_root` setting, then asks for the sudo password once if any of the programs
+/// needs root. The password is handed to each of them as it starts.
+fn prepare_privileges(settings: &settings::SudoSettings, programs: &[&Program]) -> Result<Option<privilege::Secret>, i32> {
+    match privilege::check_root(settings) {
+        Ok(Some(warning)) => eprintln!("Warning: {}", warning),
+        Ok(None) => {}
+        Err(reason) => {
+            eprintln!("{}", reason);
+            return Err(EXIT_FAILURE);
+        }
+    }
+    if !privilege::wants_password(settings, programs.iter().copied()) {
+        return Ok(None);
+    }
+    let root: Vec<String> = programs.iter().filter(|program| program.requires_root).map(|program| program.key()).collect();
+    eprintln!("Needs root: {}", root.join(", "));
+    // As many tries as sudo itself gives
+    for _ in 0..3 {
+        let password = match privilege::read_password("[sudo] password: ") {
+            Ok(password) => password,
+            Err(e) => {
+                eprintln!("sudo authentication failed: {}", e);
+                return Err(EXIT_FAILURE);
+            }
+        };
+        match privilege::authenticate(&password) {
+            Ok(()) => return Ok(Some(password)),
+            Err(e) => eprintln!("{}", e),
+        }
+    }
+    eprintln!("sudo authentication failed");
+    Err(EXIT_FAILURE)
+}
+
+/// Checks the program's pinned checksum and parameters, runs its script and returns the script's
+/// exit code.
+fn execute_program(program: &Program, given: &HashMap<String, String>, allow_modified: bool, sudo_password: Option<&privilege::Secret>) -> i32 {
+    match integrity::verify(program) {
+        Ok(Integrity::Mismatch { file, expected, actual }) => {
+            logging::warn("Script checksum mismatch", json!({ "program": program.key(), "file": file, "expected": expected, "actual": actual, "allowed": allow_modified }));
+            if !allow_modified {
+                eprintln!("{} has changed since it was pinned", file.display());
+                eprintln!("  expected sha256 {}\n  actual   sha256 {}", expected, actual);
+                eprintln!("Review the changes, then run `linuxtoolbox pin {}` or pass --allow-modified", program.key());
+                return EXIT_INTEGRITY;
+            }
+            eprintln!("Warning: {} does not match its pinned checksum, running anyway", file.display());
+        }
+        Ok(_) => {}
+        Err(e) => {
+            eprintln!("Cannot verify {}: {}", program.script.display(), e);
+            record_run(program, Local::now(), None, false, Some(e.to_string()), None);
+            logging::error("Script checksum failed", json!({ "program": program.key(), "script": program.script, "error": e.to_string() }));
+            return EXIT_INTEGRITY;
+        }
+    }
+
+    let values = match params::resolve_values(&program.key(), &program.params, given) {
+        Ok(values) => values,
+        Err(e) => {
+            eprintln!("{}: {}", program.key(), e);
+            if !program.params.is_empty() {
+                let names: Vec<String> = program.params.iter().map(|param| format!("{} ({})", param.name, param.type_name())).collect();
+                eprintln!("Parameters: {}", names.join(", "));
+            }
+            return EXIT_USAGE;
+        }
+    };
+    if !given.is_empty() {
+        if let Err(e) = params::save_values(&program.key(), &program.params, &values) {
+            logging::warn("Failed to remember parameter values", json!({ "program": program.key(), "error": e.to_string() }));
+        }
+    }
+    let mut invocation = params::Invocation::new(&program.params, &values);
+    if let Some(password) = sudo_password.filter(|_| program.requires_root) {
+        privilege::prepare(&mut invocation, password.clone());
+    }
+
+    let names: Vec<&str> = program.params.iter().map(|param| param.name.as_str()).collect();
+    logging::info("CLI run", json!({ "program": program.key(), "script": program.script, "params": names }));
+    match execute_and_record(program, &invocation) {
+        Ok(status) => {
+            let code = status.code().unwrap_or_else(|| 128 + status.signal().unwrap_or(0));
+            let level = if status.success() { logging::Level::Info } else { logging::Level::Warn };
+            logging::log(level, "Script finished", json!({ "program": program.key(), "script": program.script, "exit_code": code }));
+            code
+        }
+        Err(e) => {
+            eprintln!("Error running script: {}", e);
+            logging::error("Script failed to start", json!({ "program": program.key(), "script": program.script, "error": e.to_string() }));
+            match e.kind() {
+                ErrorKind::NotFound => EXIT_SCRIPT_NOT_FOUND,
+                ErrorKind::PermissionDenied => EXIT_SCRIPT_NOT_EXECUTABLE,
+                _ => EXIT_FAILURE,
+            }
+        }
+    }
+}
+
+fn list_profiles(categories: &[Category], profiles: &[Profile], json: bool) {
+    if json {
+        let entries: Vec<_> = profiles
+            .iter()
+            .map(|profile| {
+                let (_, missing) = profile.resolve(categories);
+                json!({
+                    "name": profile.name,
+                    "description": profile.description,
+                    "programs": profile.programs,
+                    "missing": missing,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::Value::Array(entries));
+        return;
+    }
+
+    for profile in profiles {
+        match &profile.description {
+            Some(description) => println!("{}  ({})", profile.name, description),
+            None => println!("{}", profile.name),
+        }
+        let (_, missing) = profile.resolve(categories);
+        for key in &profile.programs {
+            if missing.contains(&key.as_str()) {
+                println!("  {}  (not in the catalog)", key);
+            } else {
+                println!("  {}", key);
+            }
+        }
+    }
+}
+
+/// Runs a profile's programs in order. Returns 0 when all of them succeeded, otherwise the exit
+/// code of the first one that failed.
+fn run_profile(
+    categories: &[Category],
+    profiles: &[Profile],
+    name: &str,
+    keep_going: bool,
+    allow_modified: bool,
+    yes: bool,
+    sudo: &settings::SudoSettings,
+) -> i32 {
+    let Some(profile) = profiles.iter().find(|p| p.name.eq_ignore_ascii_case(name)) else {
+        eprintln!("No profile named '{}'", name);
+        if !profiles.is_empty() {
+            eprintln!("Profiles: {}", profiles.iter().map(|p| p.name.as_str()).collect::<Vec<_>>().join(", "));
+        }
+        return EXIT_UNKNOWN_PROGRAM;
+    };
+    let (programs, missing) = profile.resolve(categories);
+    if !missing.is_empty() {
+        eprintln!("Profile {} names programs that are not in the catalog: {}", profile.name, missing.join(", "));
+        return EXIT_UNKNOWN_PROGRAM;
+    }
+    if !confirm_risks(&programs, yes) {
+        return EXIT_FAILURE;
+    }
+    let sudo_password = match prepare_privileges(sudo, &programs) {
+        Ok(password) => password,
+        Err(code) => return code,
+    };
+
+    logging::info("Profile started", json!({ "profile": profile.name, "programs": profile.programs, "keep_going": keep_going }));
+    let mut results: Vec<(&Program, Option<i32>)> = programs.iter().map(|program| (*program, None)).collect();
+    for (index, (program, result)) in results.iter_mut().enumerate() {
+        println!("==> [{}/{}] {}", index + 1, programs.len(), program.key());
+        let code = execute_program(program, &HashMap::new(), allow_modified, sudo_password.as_ref());
+        *result = Some(code);
+        if code != EXIT_OK && !keep_going {
+            break;
+        }
+    }
+
+    println!("\nProfile {}:", profile.name);
+    for (program, result) in &results {
+        let status = match result {
+            Some(EXIT_OK) => "ok".to_string(),
+            Some(code) => format!("failed (exit {})", code),
+            None => "skipped".to_string(),
+        };
+        println!("  {:<18} {}", status, program.key());
+    }
+    let first_failure = results.iter().filter_map(|(_, result)| *result).find(|code| *code != EXIT_OK);
+    logging::info("Profile finished", json!({ "profile": profile.name, "exit_code": first_failure.unwrap_or(EXIT_OK) }));
+    first_failure.unwrap_or(EXIT_OK)
+}
+
+fn run_doctor(categories: &[Category], fix: bool, json: bool) -> i32 {
+    let mut findings = doctor::diagnose(categories);
+    let mut fixed = Vec::new();
+    if fix {
+        findings.retain(|finding| {
+            if finding.fix.is_none() {
+                return true;
+            }
+            match doctor::apply_fix(finding) {
+                Ok(()) => {
+                    logging::info("Doctor fix applied", json!({ "path": finding.path, "fix": finding.fix }));
+                    fixed.push(finding.clone());
+                    false
+                }
+                Err(e) => {
+                    eprintln!("Could not fix {}: {}", finding.path.display(), e);
+                    true
+                }
+            }
+        });
+    }
+
+    if json {
+        println!("{}", json!({ "findings": findings, "fixed": fixed }));
+    } else {
+        for finding in &fixed {
+            println!("fixed    {}  ({})", finding.path.display(), finding.fix.as_ref().map_or("", |f| f.description()));
+        }
+        for finding in &findings {
+            let subject = finding.program.clone().unwrap_or_else(|| finding.path.display().to_string());
+            println!("{:<8} {}: {}", finding.severity.display_name(), subject, finding.message);
+            if finding.program.is_some() {
+                println!("{:<8} {}", "", finding.path.display());
+            }
+            if let Some(fix) = &finding.fix {
+                println!("{:<8} fix: {} (run `linuxtoolbox doctor --fix`)", "", fix.description());
+            }
+        }
+        if findings.is_empty() && fixed.is_empty() {
+            println!("No problems found");
+        }
+    }
+
+    if findings.iter().any(|finding| finding.severity != Severity::Info) { EXIT_FAILURE } else { EXIT_OK }
+}
+
+/// Writes checksums to the lock file of the catalog layer each program comes from. Pinning
+/// everything rewrites the lock files, so entries for removed programs disappear.
+fn pin(categories: &[Category], targets: &[String]) -> i32 {
+    let programs: Vec<&Program> = if targets.is_empty() {
+        categories.iter().flat_map(|c| c.programs.iter()).collect()
+    } else {
+        let mut found = Vec::new();
+        for target in targets {
+            match find_program(categories, target) {
+                Ok((_, program)) => found.push(program),
+                Err(e) => {
+                    eprintln!("{}", e);
+                    return EXIT_UNKNOWN_PROGRAM;
+                }
+            }
+        }
+        found
+    };
+
+    let mut layers: BTreeMap<PathBuf, (&CatalogLayer, Vec<&Program>)> = BTreeMap::new();
+    for program in programs {
+        if program.source.source == CatalogSource::Embedded {
+            println!("skipped  {}: built into the binary", program.key());
+        } else if program.sha256.is_some() {
+            println!("skipped  {}: pinned in config.toml", program.key());
+        } else {
+            layers.entry(program.source.path.clone()).or_insert((&program.source, Vec::new())).1.push(program);
+        }
+    }
+
+    let mut code = EXIT_OK;
+    for (layer, programs) in layers.into_values() {
+        let mut lock = if targets.is_empty() {
+            LockFile::default()
+        } else {
+            match integrity::load_lock(layer) {
+                Ok(lock) => lock,
+                Err(e) => {
+                    eprintln!("Cannot read {}: {}", integrity::lock_path(layer).display(), e);
+                    code = EXIT_FAILURE;
+                    continue;
+                }
+            }
+        };
+
+        let mut pinned = 0;
+        for program in &programs {
+            match integrity::sha256_file(&program.script).and_then(|sha256| Ok((sha256, integrity::sha256_sources(&program.script)?))) {
+                Ok((sha256, sources)) => {
+                    let script = program.script.display().to_string();
+                    lock.programs.insert(program.key(), LockEntry { script, sha256, sources });
+                    pinned += 1;
+                }
+                Err(e) => {
+                    eprintln!("Cannot hash {}: {}", program.script.display(), e);
+                    code = EXIT_FAILURE;
+                }
+            }
+        }
+
+        match integrity::save_lock(layer, &lock) {
+            Ok(path) => {
+                println!("pinned   {} programs in {}", pinned, path.display());
+                logging::info("Scripts pinned", json!({ "lock": path, "programs": pinned }));
+            }
+            Err(e) => {
+                eprintln!("Cannot write {}: {}", integrity::lock_path(layer).display(), e);
+                code = EXIT_FAILURE;
+            }
+        }
+    }
+    code
+}
+
+fn run_update(update_source: &update::UpdateSource, check_only: bool, skip_checksum: bool) -> i32 {
+    let release = match update::newer_release(update_source) {
+        Ok(Some(release)) => release,
+        Ok(None) => {
+            println!("linuxtoolbox {} is the latest version", CURRENT_VERSION);
+            return EXIT_OK;
+        }
+        Err(e) => {
+            eprintln!("Update check failed: {}", e);
+            return EXIT_FAILURE;
+        }
+    };
+
+    println!("Update available: {} (current v{})", release.tag_name, CURRENT_VERSION);
+    if check_only {
+        return EXIT_OK;
+    }
+    match update::install(&release, !skip_checksum) {
+        Ok(path) => {
+            println!("Installed {} to {}", release.tag_name, path.display());
+            EXIT_OK
+        }
+        Err(e) => {
+            eprintln!("Update failed: {}", e);
+            logging::error("Update failed", json!({ "tag": release.tag_name, "error": e.to_string() }));
+            EXIT_FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Option<CliCommand>, String> {
+        parse_args(&args.iter().map(|a| a.to_string()).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn json_is_only_accepted_where_it_is_supported() {
+        assert!(matches!(parse(&["list", "--json"]), Ok(Some(CliCommand::List { json: true }))));
+        assert!(matches!(parse(&["doctor", "--json"]), Ok(Some(CliCommand::Doctor { json: true, .. }))));
+        assert_eq!(parse(&["info", "--json"]).err(), Some("Unknown option: --json".to_string()));
+        assert!(parse(&["pin", "--json"]).is_err());
+        assert!(parse(&["update", "--json"]).is_err());
+    }
+
+    #[test]
+    fn run_collects_parameters() {
+        let Ok(Some(CliCommand::Run { target, params, yes, .. })) = parse(&["run", "Utilities/WireGuard", "port=51821", "--yes"]) else {
+            panic!("expected a run command");
+        };
+        assert_eq!(target, "Utilities/WireGuard");
+        assert_eq!(params.get("port").map(String::as_str), Some("51821"));
+        assert!(yes);
+        assert!(parse(&["run", "Utilities/WireGuard", "port"]).is_err());
+    }
+
+    #[test]
+    fn no_command_starts_the_menu() {
+        assert!(matches!(parse(&[]), Ok(None)));
+    }
+}
@@ -0,0 +1,157 @@
+use std::path::{Path, PathBuf};
+
+use crate::embedded;
+
+const CONFIG_FILE: &str = "config.toml";
+const SYSTEM_CONFIG_DIR: &str = "/etc/linuxtoolbox";
+
+/// Where a catalog layer was found, lowest precedence first.
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+pub enum CatalogSource {
+    /// Built into the binary, the base whenever no bundled, system or local catalog exists
+    Embedded,
+    /// Next to the executable, e.g. an unpacked release archive
+    Bundled,
+    System,
+    User,
+    /// config.toml in the current directory, e.g. a repo checkout
+    Local,
+    /// Given with --config
+    Explicit,
+}
+
+impl CatalogSource {
+    pub fn display_name(&self) -> &str {
+        match self {
+            CatalogSource::Embedded => "built-in",
+            CatalogSource::Bundled => "bundled",
+            CatalogSource::System => "system",
+            CatalogSource::User => "user",
+            CatalogSource::Local => "local",
+            CatalogSource::Explicit => "--config",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct CatalogLayer {
+    pub source: CatalogSource,
+    pub path: PathBuf,
+}
+
+/// `$XDG_CONFIG_HOME/linuxtoolbox`, falling back to `~/.config/linuxtoolbox`.
+pub fn user_config_dir() -> PathBuf {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .unwrap_or_else(|| PathBuf::from("."));
+    base.join("linuxtoolbox")
+}
+
+/// Finds every catalog layer that exists, lowest precedence first. Later layers override programs
+/// of the same name in earlier ones. User and `--config` catalogs usually only add or tweak a few
+/// programs, so without a full catalog on disk the built-in one goes underneath them.
+pub fn discover_layers(explicit: Option<&Path>) -> Result<Vec<CatalogLayer>, Box<dyn std::error::Error>> {
+    let mut candidates = Vec::new();
+    if let Some(dir) = std::env::current_exe().ok().as_deref().and_then(Path::parent) {
+        candidates.push((CatalogSource::Bundled, dir.join(CONFIG_FILE)));
+    }
+    candidates.push((CatalogSource::System, Path::new(SYSTEM_CONFIG_DIR).join(CONFIG_FILE)));
+    candidates.push((CatalogSource::User, user_config_dir().join(CONFIG_FILE)));
+    candidates.push((CatalogSource::Local, PathBuf::from(CONFIG_FILE)));
+
+    if let Some(path) = explicit {
+        if !path.is_file() {
+            return Err(format!("Config file not found: {}", path.display()).into());
+        }
+        candidates.push((CatalogSource::Explicit, path.to_path_buf()));
+    }
+
+    let mut layers = existing_layers(candidates);
+    if !has_full_catalog(&layers) {
+        layers.insert(0, CatalogLayer {
+            source: CatalogSource::Embedded,
+            path: embedded::extract_catalog()?,
+        });
+    }
+    Ok(layers)
+}
+
+/// The candidates that exist, in order. A file reached twice, e.g. running the binary from the repo
+/// root, counts once, in the later and stronger of its places.
+fn existing_layers(candidates: Vec<(CatalogSource, PathBuf)>) -> Vec<CatalogLayer> {
+    let mut layers: Vec<CatalogLayer> = Vec::new();
+    let mut seen = Vec::new();
+    for (source, path) in candidates {
+        let Ok(canonical) = path.canonicalize() else { continue };
+        if let Some(index) = seen.iter().position(|p| p == &canonical) {
+            layers.remove(index);
+            seen.remove(index);
+        }
+        seen.push(canonical);
+        layers.push(CatalogLayer { source, path });
+    }
+    layers
+}
+
+fn has_full_catalog(layers: &[CatalogLayer]) -> bool {
+    layers
+        .iter()
+        .any(|layer| matches!(layer.source, CatalogSource::Bundled | CatalogSource::System | CatalogSource::Local))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sources(layers: &[CatalogLayer]) -> Vec<&str> {
+        layers.iter().map(|layer| layer.source.display_name()).collect()
+    }
+
+    #[test]
+    fn existing_layers_skip_missing_files_and_keep_the_order() {
+        let dir = std::env::temp_dir().join(format!("linuxtoolbox-config-order-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        for name in ["system", "user", "local"] {
+            std::fs::create_dir_all(dir.join(name)).unwrap();
+            std::fs::write(dir.join(name).join(CONFIG_FILE), "").unwrap();
+        }
+        let layers = existing_layers(vec![
+            (CatalogSource::Bundled, dir.join("missing").join(CONFIG_FILE)),
+            (CatalogSource::System, dir.join("system").join(CONFIG_FILE)),
+            (CatalogSource::User, dir.join("user").join(CONFIG_FILE)),
+            (CatalogSource::Local, dir.join("local").join(CONFIG_FILE)),
+        ]);
+        assert_eq!(sources(&layers), ["system", "user", "local"]);
+        assert_eq!(layers[1].path, dir.join("user").join(CONFIG_FILE));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn existing_layers_count_a_file_once_in_its_strongest_place() {
+        let dir = std::env::temp_dir().join(format!("linuxtoolbox-config-dedup-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("user")).unwrap();
+        std::fs::write(dir.join(CONFIG_FILE), "").unwrap();
+        std::fs::write(dir.join("user").join(CONFIG_FILE), "").unwrap();
+        // The binary sits in the repo that is also the current directory
+        let layers = existing_layers(vec![
+            (CatalogSource::Bundled, dir.join(CONFIG_FILE)),
+            (CatalogSource::User, dir.join("user").join(CONFIG_FILE)),
+            (CatalogSource::Local, dir.join("user").join("..").join(CONFIG_FILE)),
+        ]);
+        assert_eq!(sources(&layers), ["user", "local"]);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn only_bundled_system_or_local_catalogs_replace_the_built_in_one() {
+        let layer = |source| CatalogLayer { source, path: PathBuf::from(CONFIG_FILE) };
+        assert!(!has_full_catalog(&[]));
+        assert!(!has_full_catalog(&[layer(CatalogSource::User), layer(CatalogSource::Explicit)]));
+        for source in [CatalogSource::Bundled, CatalogSource::System, CatalogSource::Local] {
+            assert!(has_full_catalog(&[layer(CatalogSource::User), layer(source)]));
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use std::collections::HashSet;
+use std::fs;
+use std::io::ErrorKind;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde::Serialize;
+
+use crate::config::CatalogSource;
+use crate::integrity::{self, Integrity};
+use crate::{check_script, Category, CategoryKind};
+
+#[derive(Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+    Info,
+}
+
+impl Severity {
+    pub fn display_name(&self) -> &str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Fix {
+    MakeExecutable,
+}
+
+impl Fix {
+    pub fn description(&self) -> &str {
+        match self {
+            Fix::MakeExecutable => "chmod +x",
+        }
+    }
+}
+
+/// One problem found in the catalog.
+#[derive(Clone, Serialize)]
+pub struct Finding {
+    pub severity: Severity,
+    /// "Category/Program", or None for scripts no program points at
+    pub program: Option<String>,
+    pub path: PathBuf,
+    pub message: String,
+    pub fix: Option<Fix>,
+}
+
+/// Checks every catalog program's script and looks for scripts nobody registered. Errors come
+/// first, then warnings, then informational findings.
+pub fn diagnose(categories: &[Category]) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let programs = categories
+        .iter()
+        .filter(|c| c.kind == CategoryKind::Catalog)
+        .flat_map(|c| c.programs.iter());
+
+    let mut registered = HashSet::new();
+    let mut script_dirs = Vec::new();
+    for program in programs {
+        registered.insert(program.script.canonicalize().unwrap_or_else(|_| program.script.clone()));
+        // Extracted built-in scripts live in a cache that only holds what has been used
+        if program.source.source != CatalogSource::Embedded {
+            let dir = program.source.path.parent().unwrap_or(Path::new(".")).join("scripts");
+            if !script_dirs.contains(&dir) {
+                script_dirs.push(dir);
+            }
+        }
+
+        let finding = |severity, message: String, fix| Finding {
+            severity,
+            program: Some(program.key()),
+            path: program.script.clone(),
+            message,
+            fix,
+        };
+        match check_script(&program.script) {
+            Ok(()) => {}
+            Err(e) if e.kind() == ErrorKind::PermissionDenied => {
+                findings.push(finding(Severity::Warning, "Script is not executable".to_string(), Some(Fix::MakeExecutable)));
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                findings.push(finding(Severity::Error, "Script not found".to_string(), None));
+                continue;
+            }
+            Err(e) => {
+                findings.push(finding(Severity::Error, e.to_string(), None));
+                continue;
+            }
+        }
+        if let Some(error) = syntax_error(&program.script) {
+            findings.push(finding(Severity::Error, format!("Syntax error: {}", error), None));
+        }
+        match integrity::verify(program) {
+            Ok(Integrity::Mismatch { file, expected, actual }) => {
+                let message = format!("{} does not match its pinned sha256 (expected {}, got {})", file.display(), expected, actual);
+                findings.push(finding(Severity::Error, message, None));
+            }
+            Ok(_) => {}
+            Err(e) => findings.push(finding(Severity::Error, format!("Cannot verify the pinned sha256: {}", e), None)),
+        }
+    }
+
+    for dir in script_dirs {
+        let Ok(entries) = fs::read_dir(&dir) else { continue };
+        let mut unregistered: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "sh"))
+            .filter(|path| !registered.contains(&path.canonicalize().unwrap_or_else(|_| path.clone())))
+            .collect();
+        unregistered.sort();
+        findings.extend(unregistered.into_iter().map(|path| Finding {
+            severity: Severity::Info,
+            program: None,
+            path,
+            message: "Script is not registered in any catalog".to_string(),
+            fix: None,
+        }));
+    }
+
+    findings.sort_by_key(|finding| finding.severity as u8);
+    findings
+}
+
+/// Runs `bash -n` and returns the first line of its complaint, if any.
+fn syntax_error(script: &Path) -> Option<String> {
+    let output = Command::new("bash").arg("-n").arg(script).output().ok()?;
+    if output.status.success() {
+        return None;
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    Some(stderr.lines().next().unwrap_or("bash -n failed").trim().to_string())
+}
+
+pub fn apply_fix(finding: &Finding) -> std::io::Result<()> {
+    match finding.fix {
+        Some(Fix::MakeExecutable) => {
+            let mut permissions = fs::metadata(&finding.path)?.permissions();
+            // Execute for everyone who can read it, like chmod +x
+            permissions.set_mode(permissions.mode() | ((permissions.mode() & 0o444) >> 2));
+            fs::set_permissions(&finding.path, permissions)
+        }
+        None => Err(std::io::Error::new(ErrorKind::Unsupported, "No automatic fix for this problem")),
+    }
+}
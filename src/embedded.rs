@@ -0,0 +1,145 @@
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Component, Path, PathBuf};
+
+use include_dir::{include_dir, Dir};
+use serde_json::json;
+
+use crate::config::CatalogSource;
+use crate::{logging, Category, Program, CURRENT_VERSION};
+
+/// Catalog used when no config.toml is found on disk.
+pub const DEFAULT_CONFIG: &str = include_str!("../config.toml");
+
+/// The scripts directory itself, so there is no second copy to keep in sync
+static SCRIPTS: Dir<'static> = include_dir!("$CARGO_MANIFEST_DIR/scripts");
+/// Helpers that scripts such as postinstall2.sh source relative to their own directory
+const FUNCTIONS_DIR: &str = "functions";
+
+/// Extraction target, versioned so an upgraded binary never runs scripts left by an older one.
+pub fn cache_dir() -> PathBuf {
+    let base = std::env::var_os("XDG_CACHE_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .unwrap_or_else(std::env::temp_dir);
+    base.join("linuxtoolbox").join(CURRENT_VERSION)
+}
+
+/// Writes the built-in catalog to the cache directory and returns its path.
+pub fn extract_catalog() -> std::io::Result<PathBuf> {
+    let dir = cache_dir();
+    let path = dir.join("config.toml");
+    write_if_changed(&path, DEFAULT_CONFIG.as_bytes(), 0o644)?;
+    Ok(path)
+}
+
+/// Extracts the embedded script with this file name, plus the shared functions it may source.
+pub fn extract_script(file_name: &str) -> std::io::Result<Option<PathBuf>> {
+    let Some(file) = SCRIPTS.get_file(file_name) else {
+        return Ok(None);
+    };
+
+    let scripts_dir = cache_dir().join("scripts");
+    if let Some(functions) = SCRIPTS.get_dir(FUNCTIONS_DIR) {
+        extract_dir(functions, &scripts_dir.join(FUNCTIONS_DIR))?;
+    }
+
+    let path = scripts_dir.join(file_name);
+    write_if_changed(&path, file.contents(), 0o755)?;
+    Ok(Some(path))
+}
+
+/// The embedded script a program may fall back to. Only the built-in and bundled catalogs name the
+/// scripts the binary carries, as `scripts/<file>`; a missing script anywhere else is an error.
+fn embedded_name(program: &Program) -> Option<String> {
+    if !matches!(program.source.source, CatalogSource::Embedded | CatalogSource::Bundled) {
+        return None;
+    }
+    let relative = program.script.strip_prefix(program.source.path.parent()?).ok()?;
+    let mut parts = relative.components();
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(Component::Normal(dir)), Some(Component::Normal(name)), None) if dir == "scripts" => name.to_str().map(str::to_string),
+        _ => None,
+    }
+}
+
+/// Points programs whose script is missing on disk at the embedded copy, if the binary has one.
+pub fn resolve_missing_scripts(categories: &mut [Category]) {
+    for program in categories.iter_mut().flat_map(|c| c.programs.iter_mut()) {
+        if program.script.exists() {
+            continue;
+        }
+        let Some(file_name) = embedded_name(program) else {
+            continue;
+        };
+        match extract_script(&file_name) {
+            Ok(Some(path)) => program.script = path,
+            Ok(None) => {}
+            Err(e) => logging::error("Failed to extract embedded script", json!({ "script": file_name, "error": e.to_string() })),
+        }
+    }
+}
+
+fn extract_dir(dir: &Dir, target: &Path) -> std::io::Result<()> {
+    for file in dir.files() {
+        let relative = file.path().strip_prefix(dir.path()).unwrap_or(file.path());
+        write_if_changed(&target.join(relative), file.contents(), 0o644)?;
+    }
+    for subdir in dir.dirs() {
+        let relative = subdir.path().strip_prefix(dir.path()).unwrap_or(subdir.path());
+        extract_dir(subdir, &target.join(relative))?;
+    }
+    Ok(())
+}
+
+fn write_if_changed(path: &Path, contents: &[u8], mode: u32) -> std::io::Result<()> {
+    if fs::read(path).map_or(true, |existing| existing != contents) {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)?;
+    }
+    fs::set_permissions(path, fs::Permissions::from_mode(mode))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CatalogLayer;
+    use crate::ProgramEntry;
+
+    fn program(source: CatalogSource, script: &str) -> Program {
+        let layer = CatalogLayer { source, path: PathBuf::from("/opt/toolbox/config.toml") };
+        Program::from_entry("Utilities", "WireGuard", ProgramEntry::Path(script.to_string()), &layer).unwrap()
+    }
+
+    #[test]
+    fn bundled_scripts_fall_back_to_the_embedded_copy() {
+        assert_eq!(embedded_name(&program(CatalogSource::Bundled, "scripts/wireguard-install.sh")).as_deref(), Some("wireguard-install.sh"));
+        assert_eq!(embedded_name(&program(CatalogSource::Embedded, "scripts/wireguard-install.sh")).as_deref(), Some("wireguard-install.sh"));
+    }
+
+    #[test]
+    fn every_built_in_script_in_the_tree_is_embedded() {
+        let catalog: toml::value::Table = toml::from_str(DEFAULT_CONFIG).unwrap();
+        let scripts = catalog.values().filter_map(toml::Value::as_table).flat_map(|programs| programs.values()).filter_map(|entry| {
+            entry.as_str().or_else(|| entry.get("script").and_then(toml::Value::as_str))
+        });
+        // Some entries name scripts that are not in the repository at all
+        for script in scripts.filter(|script| Path::new(env!("CARGO_MANIFEST_DIR")).join(script).is_file()) {
+            let name = script.strip_prefix("scripts/").unwrap_or(script);
+            assert!(SCRIPTS.get_file(name).is_some(), "{} is not embedded", script);
+        }
+        assert!(SCRIPTS.get_dir(FUNCTIONS_DIR).is_some());
+    }
+
+    #[test]
+    fn other_scripts_never_fall_back() {
+        assert_eq!(embedded_name(&program(CatalogSource::User, "scripts/wireguard-install.sh")), None);
+        assert_eq!(embedded_name(&program(CatalogSource::Explicit, "/opt/site/wireguard-install.sh")), None);
+        assert_eq!(embedded_name(&program(CatalogSource::Bundled, "/opt/site/wireguard-install.sh")), None);
+        assert_eq!(embedded_name(&program(CatalogSource::Bundled, "wireguard-install.sh")), None);
+        assert_eq!(embedded_name(&program(CatalogSource::Bundled, "scripts/site/wireguard-install.sh")), None);
+    }
+}
@@ -0,0 +1,200 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::config::{CatalogLayer, CatalogSource};
+use crate::{preview, Program};
+
+/// Written by `linuxtoolbox pin` next to each catalog's config.toml
+pub const LOCK_FILE: &str = "linuxtoolbox.lock";
+const LOCK_HEADER: &str = "# Generated by `linuxtoolbox pin`. Commit it next to config.toml; re-run pin after reviewing script changes.\n\n";
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct LockFile {
+    #[serde(default)]
+    pub programs: BTreeMap<String, LockEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct LockEntry {
+    /// Script path as it was when pinned, for people reading the file
+    pub script: String,
+    pub sha256: String,
+    /// Checksums of the files the script sources, by path
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub sources: BTreeMap<String, String>,
+}
+
+/// Whether a script still matches its pinned checksum.
+pub enum Integrity {
+    Unpinned,
+    Verified,
+    /// `file` is the script or one of the files it sources
+    Mismatch { file: PathBuf, expected: String, actual: String },
+}
+
+pub fn lock_path(layer: &CatalogLayer) -> PathBuf {
+    layer.path.parent().unwrap_or(Path::new(".")).join(LOCK_FILE)
+}
+
+/// Reads the lock file that belongs to a catalog layer. A missing file is an empty lock.
+pub fn load_lock(layer: &CatalogLayer) -> Result<LockFile, Box<dyn std::error::Error>> {
+    // The built-in catalog ships inside the binary, so there is nothing on disk to pin
+    if layer.source == CatalogSource::Embedded {
+        return Ok(LockFile::default());
+    }
+    match fs::read_to_string(lock_path(layer)) {
+        Ok(contents) => Ok(toml::from_str(&contents)?),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(LockFile::default()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+pub fn save_lock(layer: &CatalogLayer, lock: &LockFile) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let path = lock_path(layer);
+    fs::write(&path, format!("{}{}", LOCK_HEADER, toml::to_string(lock)?))?;
+    Ok(path)
+}
+
+pub fn sha256_file(path: &Path) -> std::io::Result<String> {
+    let mut file = fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buf = [0u8; 8192];
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(hasher.finalize().iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+/// Files the script sources, directly or through other sourced files, that exist on disk.
+pub fn sourced_files(script: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut seen: HashSet<PathBuf> = HashSet::new();
+    let mut pending = vec![script.to_path_buf()];
+    while let Some(path) = pending.pop() {
+        if !seen.insert(path.canonicalize().unwrap_or_else(|_| path.clone())) {
+            continue;
+        }
+        let Ok(raw) = fs::read(&path) else { continue };
+        let text = String::from_utf8_lossy(&raw);
+        pending.extend(preview::find_sources(&text, &path).into_iter().filter_map(|source| source.resolved).filter(|p| p.is_file()));
+        if path != script {
+            files.push(path);
+        }
+    }
+    files.sort();
+    files
+}
+
+/// Checksums of everything the script sources, for its lock entry.
+pub fn sha256_sources(script: &Path) -> std::io::Result<BTreeMap<String, String>> {
+    sourced_files(script).into_iter().map(|path| Ok((path.display().to_string(), sha256_file(&path)?))).collect()
+}
+
+/// Hashes the program's script and compares it with the pin from config.toml, or else the lock
+/// file. Files the script sourced when it was pinned are checked against the lock file too.
+pub fn verify(program: &Program) -> std::io::Result<Integrity> {
+    let Some(expected) = program.sha256.as_ref().or(program.locked_sha256.as_ref()) else {
+        return Ok(Integrity::Unpinned);
+    };
+    let actual = sha256_file(&program.script)?;
+    if !actual.eq_ignore_ascii_case(expected) {
+        return Ok(Integrity::Mismatch { file: program.script.clone(), expected: expected.to_lowercase(), actual });
+    }
+    for (path, expected) in &program.locked_sources {
+        let file = PathBuf::from(path);
+        let actual = sha256_file(&file).map_err(|e| std::io::Error::new(e.kind(), format!("{}: {}", file.display(), e)))?;
+        if !actual.eq_ignore_ascii_case(expected) {
+            return Ok(Integrity::Mismatch { file, expected: expected.to_lowercase(), actual });
+        }
+    }
+    Ok(Integrity::Verified)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProgramEntry;
+
+    const EMPTY_SHA256: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+    /// A catalog with one script that sources functions/lib.sh.
+    fn scratch_program(name: &str) -> (PathBuf, Program) {
+        let dir = std::env::temp_dir().join(format!("linuxtoolbox-integrity-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("functions")).unwrap();
+        fs::write(dir.join("tool.sh"), "#!/bin/bash\nsource \"$(dirname \"$0\")/functions/lib.sh\"\n").unwrap();
+        fs::write(dir.join("functions/lib.sh"), "").unwrap();
+        let layer = CatalogLayer { source: CatalogSource::User, path: dir.join("config.toml") };
+        let program = Program::from_entry("Test", "Tool", ProgramEntry::Path("tool.sh".to_string()), &layer).unwrap();
+        (dir, program)
+    }
+
+    fn pin(program: &mut Program) {
+        program.locked_sha256 = Some(sha256_file(&program.script).unwrap());
+        program.locked_sources = sha256_sources(&program.script).unwrap();
+    }
+
+    #[test]
+    fn sha256_sources_covers_sourced_files() {
+        let (dir, program) = scratch_program("sources");
+        let sources = sha256_sources(&program.script).unwrap();
+        let lib = dir.join("functions/lib.sh").display().to_string();
+        assert_eq!(sources.into_iter().collect::<Vec<_>>(), [(lib, EMPTY_SHA256.to_string())]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn verify_accepts_unchanged_files() {
+        let (dir, mut program) = scratch_program("verified");
+        assert!(matches!(verify(&program).unwrap(), Integrity::Unpinned));
+        pin(&mut program);
+        assert!(matches!(verify(&program).unwrap(), Integrity::Verified));
+        // Pins copied from elsewhere may be upper case
+        program.locked_sha256 = program.locked_sha256.map(|sha| sha.to_uppercase());
+        assert!(matches!(verify(&program).unwrap(), Integrity::Verified));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn verify_reports_the_file_that_changed() {
+        let (dir, mut program) = scratch_program("mismatch");
+        pin(&mut program);
+        let lib = dir.join("functions/lib.sh");
+        fs::write(&lib, "echo changed\n").unwrap();
+        match verify(&program).unwrap() {
+            Integrity::Mismatch { file, expected, actual } => {
+                assert_eq!(file, lib);
+                assert_eq!(expected, EMPTY_SHA256);
+                assert_eq!(actual, sha256_file(&lib).unwrap());
+            }
+            _ => panic!("a changed sourced file must not verify"),
+        }
+
+        fs::write(&program.script, "#!/bin/bash\n").unwrap();
+        assert!(matches!(verify(&program).unwrap(), Integrity::Mismatch { file, .. } if file == program.script));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn verify_prefers_the_config_pin_and_fails_on_missing_sources() {
+        let (dir, mut program) = scratch_program("config-pin");
+        pin(&mut program);
+        program.sha256 = Some(EMPTY_SHA256.to_string());
+        assert!(matches!(verify(&program).unwrap(), Integrity::Mismatch { expected, .. } if expected == EMPTY_SHA256));
+
+        program.sha256 = None;
+        fs::remove_file(dir.join("functions/lib.sh")).unwrap();
+        let error = verify(&program).err().unwrap();
+        assert!(error.to_string().contains("lib.sh"), "{}", error);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,561 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::{config, logging};
+
+const KEYMAP_FILE: &str = "keys.toml";
+
+/// A group of bindings. Global bindings apply on every screen that does not bind the key itself.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Screen {
+    Global,
+    Categories,
+    Programs,
+    Search,
+    History,
+    Doctor,
+    Preview,
+    Themes,
+    Queue,
+    Profiles,
+    Form,
+    Confirm,
+    Sudo,
+    Help,
+    SystemInfo,
+}
+
+impl Screen {
+    const ALL: [Screen; 15] = [
+        Screen::Global,
+        Screen::Categories,
+        Screen::Programs,
+        Screen::Search,
+        Screen::History,
+        Screen::Doctor,
+        Screen::Preview,
+        Screen::Themes,
+        Screen::Queue,
+        Screen::Profiles,
+        Screen::Form,
+        Screen::Confirm,
+        Screen::Sudo,
+        Screen::Help,
+        Screen::SystemInfo,
+    ];
+
+    /// Section name in keys.toml
+    pub fn name(&self) -> &'static str {
+        match self {
+            Screen::Global => "global",
+            Screen::Categories => "categories",
+            Screen::Programs => "programs",
+            Screen::Search => "search",
+            Screen::History => "history",
+            Screen::Doctor => "doctor",
+            Screen::Preview => "preview",
+            Screen::Themes => "themes",
+            Screen::Queue => "queue",
+            Screen::Profiles => "profiles",
+            Screen::Form => "form",
+            Screen::Confirm => "confirm",
+            Screen::Sudo => "sudo",
+            Screen::Help => "help",
+            Screen::SystemInfo => "system_info",
+        }
+    }
+
+    /// Screens where typing goes into a text field, so plain keys never trigger a shortcut.
+    fn is_text_entry(&self) -> bool {
+        matches!(self, Screen::Search | Screen::Form | Screen::Confirm | Screen::Sudo)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Action {
+    Quit,
+    Help,
+    SystemInfo,
+    History,
+    Doctor,
+    Themes,
+    Profiles,
+    NextTheme,
+    InstallUpdate,
+    /// Answers yes to the quit and checksum questions
+    Yes,
+    Up,
+    Down,
+    Top,
+    Bottom,
+    Select,
+    Back,
+    Search,
+    SearchContents,
+    Preview,
+    Favorite,
+    Mark,
+    RunQueue,
+    Output,
+    Fix,
+    FixAll,
+}
+
+impl Action {
+    const ALL: [Action; 25] = [
+        Action::Quit,
+        Action::Help,
+        Action::SystemInfo,
+        Action::History,
+        Action::Doctor,
+        Action::Themes,
+        Action::Profiles,
+        Action::NextTheme,
+        Action::InstallUpdate,
+        Action::Yes,
+        Action::Up,
+        Action::Down,
+        Action::Top,
+        Action::Bottom,
+        Action::Select,
+        Action::Back,
+        Action::Search,
+        Action::SearchContents,
+        Action::Preview,
+        Action::Favorite,
+        Action::Mark,
+        Action::RunQueue,
+        Action::Output,
+        Action::Fix,
+        Action::FixAll,
+    ];
+
+    /// Key name in keys.toml
+    pub fn name(&self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::Help => "help",
+            Action::SystemInfo => "system_info",
+            Action::History => "history",
+            Action::Doctor => "doctor",
+            Action::Themes => "themes",
+            Action::Profiles => "profiles",
+            Action::NextTheme => "next_theme",
+            Action::InstallUpdate => "install_update",
+            Action::Yes => "yes",
+            Action::Up => "up",
+            Action::Down => "down",
+            Action::Top => "top",
+            Action::Bottom => "bottom",
+            Action::Select => "select",
+            Action::Back => "back",
+            Action::Search => "search",
+            Action::SearchContents => "search_contents",
+            Action::Preview => "preview",
+            Action::Favorite => "favorite",
+            Action::Mark => "mark",
+            Action::RunQueue => "run_queue",
+            Action::Output => "output",
+            Action::Fix => "fix",
+            Action::FixAll => "fix_all",
+        }
+    }
+}
+
+/// Built-in bindings. Only these screen/action pairs can be rebound.
+const DEFAULTS: &[(Screen, Action, &[&str])] = &[
+    (Screen::Global, Action::Quit, &["q"]),
+    (Screen::Global, Action::Help, &["h"]),
+    (Screen::Global, Action::SystemInfo, &["i"]),
+    (Screen::Global, Action::History, &["r"]),
+    (Screen::Global, Action::Doctor, &["d"]),
+    (Screen::Global, Action::Themes, &["t"]),
+    (Screen::Global, Action::Profiles, &["P"]),
+    (Screen::Global, Action::NextTheme, &["tab"]),
+    (Screen::Global, Action::InstallUpdate, &["u"]),
+    (Screen::Global, Action::Yes, &["y"]),
+    (Screen::Categories, Action::Up, &["up"]),
+    (Screen::Categories, Action::Down, &["down"]),
+    (Screen::Categories, Action::Top, &["home"]),
+    (Screen::Categories, Action::Select, &["enter"]),
+    (Screen::Categories, Action::Search, &["/"]),
+    (Screen::Categories, Action::RunQueue, &["x"]),
+    (Screen::Programs, Action::Up, &["up"]),
+    (Screen::Programs, Action::Down, &["down"]),
+    (Screen::Programs, Action::Top, &["home"]),
+    (Screen::Programs, Action::Select, &["enter"]),
+    (Screen::Programs, Action::Preview, &["p"]),
+    (Screen::Programs, Action::Favorite, &["f"]),
+    (Screen::Programs, Action::Mark, &["space"]),
+    (Screen::Programs, Action::RunQueue, &["x"]),
+    (Screen::Programs, Action::Search, &["/"]),
+    (Screen::Programs, Action::Back, &["esc", "backspace"]),
+    (Screen::Search, Action::Up, &["up"]),
+    (Screen::Search, Action::Down, &["down"]),
+    (Screen::Search, Action::Select, &["enter"]),
+    (Screen::Search, Action::Back, &["esc"]),
+    (Screen::Search, Action::SearchContents, &["tab"]),
+    (Screen::Search, Action::Mark, &["ctrl+space"]),
+    (Screen::Search, Action::RunQueue, &["ctrl+x"]),
+    (Screen::History, Action::Up, &["up"]),
+    (Screen::History, Action::Down, &["down"]),
+    (Screen::History, Action::Top, &["home"]),
+    (Screen::History, Action::Select, &["enter"]),
+    (Screen::History, Action::Preview, &["p"]),
+    (Screen::History, Action::Output, &["o"]),
+    (Screen::History, Action::Back, &["esc", "backspace"]),
+    (Screen::Doctor, Action::Up, &["up"]),
+    (Screen::Doctor, Action::Down, &["down"]),
+    (Screen::Doctor, Action::Top, &["home"]),
+    (Screen::Doctor, Action::Fix, &["enter", "x"]),
+    (Screen::Doctor, Action::FixAll, &["a"]),
+    (Screen::Doctor, Action::Back, &["esc", "backspace"]),
+    (Screen::Preview, Action::Select, &["enter"]),
+    (Screen::Preview, Action::Back, &["esc", "backspace"]),
+    (Screen::Themes, Action::Up, &["up"]),
+    (Screen::Themes, Action::Down, &["down"]),
+    (Screen::Themes, Action::Top, &["home"]),
+    (Screen::Themes, Action::Bottom, &["end"]),
+    (Screen::Themes, Action::Select, &["enter"]),
+    (Screen::Themes, Action::Back, &["esc", "backspace"]),
+    (Screen::Profiles, Action::Up, &["up"]),
+    (Screen::Profiles, Action::Down, &["down"]),
+    (Screen::Profiles, Action::Top, &["home"]),
+    (Screen::Profiles, Action::Select, &["enter"]),
+    (Screen::Profiles, Action::Back, &["esc", "backspace"]),
+    (Screen::Form, Action::Up, &["up"]),
+    (Screen::Form, Action::Down, &["down", "tab"]),
+    (Screen::Form, Action::Select, &["enter"]),
+    (Screen::Form, Action::Back, &["esc"]),
+    (Screen::Confirm, Action::Select, &["enter"]),
+    (Screen::Confirm, Action::Back, &["esc"]),
+    (Screen::Sudo, Action::Select, &["enter"]),
+    (Screen::Sudo, Action::Back, &["esc"]),
+    (Screen::Queue, Action::Select, &["enter"]),
+    (Screen::Queue, Action::Back, &["esc", "backspace"]),
+    (Screen::Help, Action::Back, &["esc"]),
+    (Screen::SystemInfo, Action::Back, &["esc"]),
+];
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct KeyBinding {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    /// Parses "q", "N", "tab", "pgdn", "f5", "ctrl+r" and the like.
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut key = spec;
+        // A lone character is always a key, so "+" can be bound too
+        if spec.chars().count() > 1 {
+            let mut parts: Vec<&str> = spec.split('+').collect();
+            key = parts.pop().unwrap_or_default();
+            for part in parts {
+                modifiers |= match part.to_ascii_lowercase().as_str() {
+                    "ctrl" | "control" => KeyModifiers::CONTROL,
+                    "alt" => KeyModifiers::ALT,
+                    "shift" => KeyModifiers::SHIFT,
+                    _ => return Err(format!("unknown modifier '{}' in '{}'", part, spec)),
+                };
+            }
+        }
+
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match key.to_ascii_lowercase().as_str() {
+                "enter" | "return" => KeyCode::Enter,
+                "esc" | "escape" => KeyCode::Esc,
+                "tab" => KeyCode::Tab,
+                "backspace" => KeyCode::Backspace,
+                "space" => KeyCode::Char(' '),
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pgup" | "pageup" => KeyCode::PageUp,
+                "pgdn" | "pagedown" => KeyCode::PageDown,
+                "del" | "delete" => KeyCode::Delete,
+                "insert" => KeyCode::Insert,
+                name => match name.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                    Some(n @ 1..=12) => KeyCode::F(n),
+                    _ => return Err(format!("unknown key '{}'", spec)),
+                },
+            },
+        };
+        Ok(KeyBinding { code, modifiers })
+    }
+
+    fn matches(&self, key: &KeyEvent) -> bool {
+        // The character itself says whether Shift was held
+        let modifiers = match key.code {
+            KeyCode::Char(_) => key.modifiers - KeyModifiers::SHIFT,
+            _ => key.modifiers,
+        };
+        self.code == key.code && self.modifiers == modifiers
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (modifier, name) in [(KeyModifiers::CONTROL, "Ctrl+"), (KeyModifiers::ALT, "Alt+"), (KeyModifiers::SHIFT, "Shift+")] {
+            if self.modifiers.contains(modifier) {
+                f.write_str(name)?;
+            }
+        }
+        match self.code {
+            KeyCode::Char(' ') => f.write_str("Space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::Enter => f.write_str("Enter"),
+            KeyCode::Esc => f.write_str("Esc"),
+            KeyCode::Tab => f.write_str("Tab"),
+            KeyCode::Backspace => f.write_str("Backspace"),
+            KeyCode::Up => f.write_str("↑"),
+            KeyCode::Down => f.write_str("↓"),
+            KeyCode::Left => f.write_str("←"),
+            KeyCode::Right => f.write_str("→"),
+            KeyCode::Home => f.write_str("Home"),
+            KeyCode::End => f.write_str("End"),
+            KeyCode::PageUp => f.write_str("PgUp"),
+            KeyCode::PageDown => f.write_str("PgDn"),
+            KeyCode::Delete => f.write_str("Del"),
+            KeyCode::Insert => f.write_str("Insert"),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            _ => f.write_str("?"),
+        }
+    }
+}
+
+/// Which keys trigger which action on each screen.
+pub struct Keymap {
+    bindings: Vec<(Screen, Action, Vec<KeyBinding>)>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let bindings = DEFAULTS
+            .iter()
+            .map(|(screen, action, keys)| {
+                let keys = keys.iter().map(|key| KeyBinding::parse(key).expect("built-in key binding")).collect();
+                (*screen, *action, keys)
+            })
+            .collect();
+        Keymap { bindings }
+    }
+}
+
+/// One key or a list of them; an empty list unbinds the action.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum KeySpec {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl Keymap {
+    /// The action bound to this key on the screen, falling back to the global bindings. On text-entry
+    /// screens plain characters are left for the text field and global bindings do not apply.
+    pub fn action(&self, screen: Screen, key: &KeyEvent) -> Option<Action> {
+        let typing = matches!(key.code, KeyCode::Char(_)) && (key.modifiers - KeyModifiers::SHIFT).is_empty();
+        if screen.is_text_entry() && typing {
+            return None;
+        }
+        let lookup = |screen: Screen| {
+            self.bindings
+                .iter()
+                .find(|(s, _, keys)| *s == screen && keys.iter().any(|binding| binding.matches(key)))
+                .map(|(_, action, _)| *action)
+        };
+        lookup(screen).or_else(|| if screen.is_text_entry() { None } else { lookup(Screen::Global) })
+    }
+
+    /// The keys for an action as shown in help text, e.g. "Esc/Backspace". Empty when unbound.
+    pub fn keys(&self, screen: Screen, action: Action) -> String {
+        let bound = |screen: Screen| self.bindings.iter().find(|(s, a, _)| *s == screen && *a == action);
+        let Some((_, _, keys)) = bound(screen).or_else(|| bound(Screen::Global)) else {
+            return String::new();
+        };
+        keys.iter().map(|key| key.to_string()).collect::<Vec<_>>().join("/")
+    }
+
+    /// A help line like "Enter: Run | p: Preview" built from the current bindings. Actions
+    /// grouped in one entry share a label; unbound ones are left out.
+    pub fn hints(&self, screen: Screen, entries: &[(&[Action], &str)]) -> String {
+        entries
+            .iter()
+            .filter_map(|(actions, label)| {
+                let keys: Vec<String> = actions.iter().map(|action| self.keys(screen, *action)).filter(|keys| !keys.is_empty()).collect();
+                (!keys.is_empty()).then(|| format!("{}: {}", keys.join("/"), label))
+            })
+            .collect::<Vec<_>>()
+            .join(" | ")
+    }
+
+    fn apply(&mut self, file: BTreeMap<String, BTreeMap<String, KeySpec>>) -> Vec<String> {
+        let mut problems = Vec::new();
+        for (section, entries) in file {
+            let Some(screen) = Screen::ALL.iter().find(|s| s.name() == section) else {
+                problems.push(format!("unknown section [{}]", section));
+                continue;
+            };
+            for (name, spec) in entries {
+                let Some(action) = Action::ALL.iter().find(|a| a.name() == name) else {
+                    problems.push(format!("unknown action '{}' in [{}]", name, section));
+                    continue;
+                };
+                let Some(binding) = self.bindings.iter_mut().find(|(s, a, _)| s == screen && a == action) else {
+                    problems.push(format!("'{}' cannot be bound in [{}]", name, section));
+                    continue;
+                };
+                let specs = match spec {
+                    KeySpec::One(key) => vec![key],
+                    KeySpec::Many(keys) => keys,
+                };
+                match specs.iter().map(|key| KeyBinding::parse(key)).collect::<Result<Vec<_>, _>>() {
+                    Ok(keys) => binding.2 = keys,
+                    Err(e) => problems.push(format!("{} for '{}' in [{}]", e, name, section)),
+                }
+            }
+        }
+        problems.extend(self.conflicts());
+        problems
+    }
+
+    /// Keys bound to two actions on the same screen. Only the first of them is ever triggered.
+    fn conflicts(&self) -> Vec<String> {
+        let mut conflicts = Vec::new();
+        for (index, (screen, action, keys)) in self.bindings.iter().enumerate() {
+            for (_, other, other_keys) in self.bindings[index + 1..].iter().filter(|(s, _, _)| s == screen) {
+                for key in keys.iter().filter(|key| other_keys.contains(key)) {
+                    conflicts.push(format!(
+                        "'{}' is bound to both '{}' and '{}' in [{}], only '{}' works",
+                        key,
+                        action.name(),
+                        other.name(),
+                        screen.name(),
+                        action.name()
+                    ));
+                }
+            }
+        }
+        conflicts
+    }
+}
+
+pub fn keymap_path() -> PathBuf {
+    config::user_config_dir().join(KEYMAP_FILE)
+}
+
+/// Loads the defaults and applies `$XDG_CONFIG_HOME/linuxtoolbox/keys.toml` over them. Entries
+/// that cannot be used are logged and keep their default keys.
+pub fn load() -> Keymap {
+    let mut keymap = Keymap::default();
+    let path = keymap_path();
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return keymap,
+        Err(e) => {
+            logging::warn("Failed to read key bindings", json!({ "path": path, "error": e.to_string() }));
+            return keymap;
+        }
+    };
+    match toml::from_str(&contents) {
+        Ok(file) => {
+            for problem in keymap.apply(file) {
+                logging::warn("Ignoring key binding", json!({ "path": path, "problem": problem }));
+            }
+        }
+        Err(e) => logging::warn("Invalid key bindings file, using defaults", json!({ "path": path, "error": e.to_string() })),
+    }
+    keymap
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    fn apply(keymap: &mut Keymap, text: &str) -> Vec<String> {
+        keymap.apply(toml::from_str(text).unwrap())
+    }
+
+    #[test]
+    fn parse_reads_names_characters_and_modifiers() {
+        let parsed = |spec: &str| KeyBinding::parse(spec).unwrap();
+        assert!(parsed("q") == KeyBinding { code: KeyCode::Char('q'), modifiers: KeyModifiers::NONE });
+        assert!(parsed("+") == KeyBinding { code: KeyCode::Char('+'), modifiers: KeyModifiers::NONE });
+        assert!(parsed("PgDn") == KeyBinding { code: KeyCode::PageDown, modifiers: KeyModifiers::NONE });
+        assert!(parsed("f5") == KeyBinding { code: KeyCode::F(5), modifiers: KeyModifiers::NONE });
+        assert!(parsed("Ctrl+Alt+r") == KeyBinding { code: KeyCode::Char('r'), modifiers: KeyModifiers::CONTROL | KeyModifiers::ALT });
+        assert_eq!(parsed("ctrl+space").to_string(), "Ctrl+Space");
+
+        assert!(KeyBinding::parse("hyper+q").err().unwrap().contains("unknown modifier"));
+        assert!(KeyBinding::parse("f13").err().unwrap().contains("unknown key"));
+        assert!(KeyBinding::parse("ctrl+").is_err());
+    }
+
+    #[test]
+    fn the_defaults_have_no_conflicts() {
+        assert!(Keymap::default().conflicts().is_empty(), "{:?}", Keymap::default().conflicts());
+    }
+
+    #[test]
+    fn apply_rebinds_and_reports_problems() {
+        let mut keymap = Keymap::default();
+        let problems = apply(
+            &mut keymap,
+            r#"
+            [global]
+            quit = ["ctrl+q", "x"]
+            help = []
+
+            [programs]
+            favorite = "hyper+f"
+            nothing = "n"
+
+            [nowhere]
+            quit = "q"
+            "#,
+        );
+        assert_eq!(keymap.keys(Screen::Programs, Action::Quit), "Ctrl+q/x");
+        assert_eq!(keymap.keys(Screen::Global, Action::Help), "");
+        // A key that failed to parse keeps the default
+        assert_eq!(keymap.keys(Screen::Programs, Action::Favorite), "f");
+        assert_eq!(problems.len(), 3, "{:?}", problems);
+        assert!(problems.iter().any(|p| p.contains("unknown modifier 'hyper'")));
+        assert!(problems.iter().any(|p| p.contains("unknown action 'nothing'")));
+        assert!(problems.iter().any(|p| p.contains("unknown section [nowhere]")));
+    }
+
+    #[test]
+    fn apply_reports_keys_bound_twice_on_a_screen() {
+        let mut keymap = Keymap::default();
+        let problems = apply(&mut keymap, "[global]\nhelp = \"q\"\n");
+        assert_eq!(problems.len(), 1, "{:?}", problems);
+        assert!(problems[0].contains("'q' is bound to both"), "{}", problems[0]);
+        // A screen binding overriding a global one is not a conflict
+        assert!(apply(&mut Keymap::default(), "[programs]\nfavorite = \"q\"\n").is_empty());
+    }
+
+    #[test]
+    fn text_entry_keeps_typed_characters() {
+        let keymap = Keymap::default();
+        let q = press(KeyCode::Char('q'), KeyModifiers::NONE);
+        assert_eq!(keymap.action(Screen::Categories, &q), Some(Action::Quit));
+        assert_eq!(keymap.action(Screen::Search, &q), None);
+        assert_eq!(keymap.action(Screen::Search, &press(KeyCode::Char('H'), KeyModifiers::SHIFT)), None);
+        assert_eq!(keymap.action(Screen::Search, &press(KeyCode::Esc, KeyModifiers::NONE)), Some(Action::Back));
+    }
+}
@@ -0,0 +1,132 @@
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+
+use chrono::Local;
+use serde_json::{Map, Value as JsonValue};
+
+use crate::state;
+
+/// Environment variable that sets the log level when `--log-level` is not given
+pub const LOG_LEVEL_ENV: &str = "LINUXTOOLBOX_LOG";
+const LOG_FILE: &str = "linuxtoolbox.log";
+/// Rotate once the active file grows past this size
+const MAX_LOG_BYTES: u64 = 1024 * 1024;
+/// Rotated files kept as linuxtoolbox.log.1 .. linuxtoolbox.log.N
+const KEEP_ROTATED: usize = 3;
+
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    pub fn parse(value: &str) -> Option<Level> {
+        match value.to_ascii_lowercase().as_str() {
+            "error" => Some(Level::Error),
+            "warn" | "warning" => Some(Level::Warn),
+            "info" => Some(Level::Info),
+            "debug" => Some(Level::Debug),
+            "trace" => Some(Level::Trace),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+struct Logger {
+    level: Level,
+    path: PathBuf,
+    // Serialises writes and rotation between the UI and script threads
+    lock: Mutex<()>,
+}
+
+static LOGGER: OnceLock<Logger> = OnceLock::new();
+
+/// Sets up the log file. The level comes from `cli_level`, then `$LINUXTOOLBOX_LOG`, then defaults to info.
+pub fn init(cli_level: Option<Level>) {
+    let level = cli_level
+        .or_else(|| std::env::var(LOG_LEVEL_ENV).ok().and_then(|value| Level::parse(&value)))
+        .unwrap_or(Level::Info);
+    let _ = LOGGER.set(Logger {
+        level,
+        path: state::state_dir().join(LOG_FILE),
+        lock: Mutex::new(()),
+    });
+}
+
+pub fn log_file() -> PathBuf {
+    LOGGER.get().map_or_else(|| state::state_dir().join(LOG_FILE), |logger| logger.path.clone())
+}
+
+/// Writes one JSON line. `fields` should be a JSON object; its keys are merged into the record.
+pub fn log(level: Level, message: &str, fields: JsonValue) {
+    let Some(logger) = LOGGER.get() else { return };
+    if level > logger.level {
+        return;
+    }
+
+    let mut record = Map::new();
+    record.insert("ts".to_string(), JsonValue::String(Local::now().to_rfc3339()));
+    record.insert("level".to_string(), JsonValue::String(level.as_str().to_string()));
+    record.insert("msg".to_string(), JsonValue::String(message.to_string()));
+    if let JsonValue::Object(fields) = fields {
+        record.extend(fields);
+    }
+
+    let _guard = logger.lock.lock();
+    if let Err(e) = write_line(&logger.path, &JsonValue::Object(record).to_string()) {
+        eprintln!("Failed to write log: {}", e);
+    }
+}
+
+pub fn error(message: &str, fields: JsonValue) {
+    log(Level::Error, message, fields);
+}
+
+pub fn warn(message: &str, fields: JsonValue) {
+    log(Level::Warn, message, fields);
+}
+
+pub fn info(message: &str, fields: JsonValue) {
+    log(Level::Info, message, fields);
+}
+
+pub fn debug(message: &str, fields: JsonValue) {
+    log(Level::Debug, message, fields);
+}
+
+fn write_line(path: &PathBuf, line: &str) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    if fs::metadata(path).is_ok_and(|meta| meta.len() >= MAX_LOG_BYTES) {
+        rotate(path)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", line)
+}
+
+fn rotate(path: &PathBuf) -> std::io::Result<()> {
+    let rotated = |n: usize| PathBuf::from(format!("{}.{}", path.display(), n));
+    for n in (1..KEEP_ROTATED).rev() {
+        if rotated(n).exists() {
+            fs::rename(rotated(n), rotated(n + 1))?;
+        }
+    }
+    fs::rename(path, rotated(1))
+}
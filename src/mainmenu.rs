@@ -1,36 +1,59 @@
+use std::collections::HashSet;
 use std::fs;
-use std::path::PathBuf;
-use std::process::Command;
-use std::io::{stdout, Stdout, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitStatus;
+use std::io::{stdout, Stdout};
 use std::os::unix::fs::PermissionsExt;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use toml::Value;
 use ratatui::{
     backend::{Backend, CrosstermBackend},
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Clear, Wrap},
     layout::{Layout, Constraint, Direction, Alignment, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Span, Line},
     Terminal, Frame,
 };
 use crossterm::{
-    event::{self, Event, KeyCode, KeyEvent, MouseEvent, MouseEventKind, EnableMouseCapture, DisableMouseCapture},
+    event::{self, Event, KeyCode, KeyEvent, MouseEventKind, EnableMouseCapture, DisableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
-    cursor::{MoveTo, Show, Hide},
+    cursor::{Show, Hide},
 };
-use reqwest::blocking::Client;
-use serde_json::Value as JsonValue;
-use semver::Version;
+use serde_json::json;
 use serde::{Deserialize, Serialize};
-use chrono::Local;
+use chrono::{DateTime, Local};
 use sysinfo::{System, SystemExt, CpuExt};
 use rand::seq::SliceRandom;
+use keymap::Action;
+
+mod cli;
+mod config;
+mod doctor;
+mod embedded;
+mod integrity;
+mod keymap;
+mod logging;
+mod output_view;
+mod params;
+mod preview;
+mod privilege;
+mod queue;
+mod search;
+mod session;
+mod settings;
+mod state;
+mod theme;
+mod update;
 
 const CURRENT_VERSION: &str = "0.6.7";
 const GITHUB_REPO: &str = "TechLogicals/LinuxToolbox";
-const COLOR_SCHEME_FILE: &str = "color_scheme.json";
-const LOG_FILE: &str = "linuxtoolbox.log";
+/// Redraw interval while a script's output is streaming into the terminal pane
+const SESSION_REFRESH_MS: u64 = 50;
+/// Redraw interval while the background update check is still running
+const UPDATE_POLL_MS: u64 = 250;
+/// Longest gap between two clicks on a program that still runs it
+const DOUBLE_CLICK_MS: u64 = 400;
 const LINUX_QUOTES: &[&str] = &[
     "Talk is cheap. Show me the code. - Linus Torvalds",
     "Given enough eyeballs, all bugs are shallow. - Eric S. Raymond",
@@ -48,176 +71,281 @@ const LINUX_QUOTES: &[&str] = &[
 struct Category {
     name: String,
     programs: Vec<Program>,
+    kind: CategoryKind,
 }
 
+/// Pseudo-categories are built from the catalog at runtime and pinned above it.
+#[derive(Clone, Copy, PartialEq)]
+enum CategoryKind {
+    Catalog,
+    Favorites,
+    Recent,
+}
+
+const FAVORITES_CATEGORY: &str = "Favorites";
+/// Top-level config.toml table holding profiles instead of programs
+const PROFILES_TABLE: &str = "profiles";
+const RECENT_CATEGORY: &str = "Recent";
+/// How many distinct programs the Recent category shows
+const RECENT_LIMIT: usize = 10;
+
 #[derive(Clone)]
 struct Program {
     name: String,
+    category: String,
     script: PathBuf,
     is_favorite: bool,
+    description: Option<String>,
+    tags: Vec<String>,
+    distros: Vec<String>,
+    requires_root: bool,
+    risk: RiskLevel,
+    /// Not tried on a real system yet, shown with a warning badge
+    untested: bool,
+    aliases: Vec<String>,
+    /// Values asked for before the script starts
+    params: Vec<params::Param>,
+    /// Checksum pinned in config.toml, which takes precedence over the lock file
+    sha256: Option<String>,
+    /// Checksum recorded by `linuxtoolbox pin`
+    locked_sha256: Option<String>,
+    /// Checksums of the files the script sourced when it was pinned, by path
+    locked_sources: std::collections::BTreeMap<String, String>,
+    /// Catalog layer that defined this program
+    source: config::CatalogLayer,
 }
 
-#[derive(PartialEq)]
-enum MenuState {
-    Categories,
-    Programs,
-    Search,
-    Help,
-    SystemInfo,
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum RiskLevel {
+    #[default]
+    Safe,
+    SystemChanging,
+    Destructive,
 }
 
-#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
-enum ColorScheme {
-    Default,
-    Dark,
-    Light,
-    Ocean,
-    Forest,
-    Sunset,
-    Neon,
-    Pastel,
-    Monochrome,
-    Autumn,
-    Winter,
-    Spring,
-    Summer,
-    Cyberpunk,
-    Retro,
-    Desert,
-    Space,
-    Candy,
-    Earth,
-    Midnight,
-    Matrix,
-    Nordic,
-    Dracula,
-    Solarized,
-    Monokai,
-    Gruvbox,
-    Tokyo,
-    Synthwave,
-    Coffee,
-    Nature,
-}
-
-impl ColorScheme {
-    fn next(&self) -> Self {
+impl RiskLevel {
+    fn display_name(&self) -> &str {
         match self {
-            ColorScheme::Default => ColorScheme::Dark,
-            ColorScheme::Dark => ColorScheme::Light,
-            ColorScheme::Light => ColorScheme::Ocean,
-            ColorScheme::Ocean => ColorScheme::Forest,
-            ColorScheme::Forest => ColorScheme::Sunset,
-            ColorScheme::Sunset => ColorScheme::Neon,
-            ColorScheme::Neon => ColorScheme::Pastel,
-            ColorScheme::Pastel => ColorScheme::Monochrome,
-            ColorScheme::Monochrome => ColorScheme::Autumn,
-            ColorScheme::Autumn => ColorScheme::Winter,
-            ColorScheme::Winter => ColorScheme::Spring,
-            ColorScheme::Spring => ColorScheme::Summer,
-            ColorScheme::Summer => ColorScheme::Cyberpunk,
-            ColorScheme::Cyberpunk => ColorScheme::Retro,
-            ColorScheme::Retro => ColorScheme::Desert,
-            ColorScheme::Desert => ColorScheme::Space,
-            ColorScheme::Space => ColorScheme::Candy,
-            ColorScheme::Candy => ColorScheme::Earth,
-            ColorScheme::Earth => ColorScheme::Midnight,
-            ColorScheme::Midnight => ColorScheme::Matrix,
-            ColorScheme::Matrix => ColorScheme::Nordic,
-            ColorScheme::Nordic => ColorScheme::Dracula,
-            ColorScheme::Dracula => ColorScheme::Solarized,
-            ColorScheme::Solarized => ColorScheme::Monokai,
-            ColorScheme::Monokai => ColorScheme::Gruvbox,
-            ColorScheme::Gruvbox => ColorScheme::Tokyo,
-            ColorScheme::Tokyo => ColorScheme::Synthwave,
-            ColorScheme::Synthwave => ColorScheme::Coffee,
-            ColorScheme::Coffee => ColorScheme::Nature,
-            ColorScheme::Nature => ColorScheme::Default,
+            RiskLevel::Safe => "safe",
+            RiskLevel::SystemChanging => "system-changing",
+            RiskLevel::Destructive => "destructive",
         }
     }
+}
 
-    fn get_colors(&self) -> (Color, Color, Color) {
-        match self {
-            ColorScheme::Default => (Color::Reset, Color::White, Color::Cyan),
-            ColorScheme::Dark => (Color::Black, Color::White, Color::Yellow),
-            ColorScheme::Light => (Color::White, Color::Black, Color::Blue),
-            ColorScheme::Ocean => (Color::Rgb(0, 105, 148), Color::White, Color::Rgb(0, 255, 255)),
-            ColorScheme::Forest => (Color::Rgb(34, 139, 34), Color::White, Color::Rgb(255, 215, 0)),
-            ColorScheme::Sunset => (Color::Rgb(255, 99, 71), Color::White, Color::Rgb(255, 215, 0)),
-            ColorScheme::Neon => (Color::Black, Color::Rgb(255, 0, 255), Color::Rgb(0, 255, 0)),
-            ColorScheme::Pastel => (Color::Rgb(255, 240, 245), Color::Rgb(70, 130, 180), Color::Rgb(255, 182, 193)),
-            ColorScheme::Monochrome => (Color::Black, Color::White, Color::Gray),
-            ColorScheme::Autumn => (Color::Rgb(139, 69, 19), Color::White, Color::Rgb(255, 140, 0)),
-            ColorScheme::Winter => (Color::Rgb(65, 105, 225), Color::White, Color::Rgb(176, 224, 230)),
-            ColorScheme::Spring => (Color::Rgb(144, 238, 144), Color::Black, Color::Rgb(255, 105, 180)),
-            ColorScheme::Summer => (Color::Rgb(255, 215, 0), Color::Black, Color::Rgb(0, 191, 255)),
-            ColorScheme::Cyberpunk => (Color::Black, Color::Rgb(0, 255, 255), Color::Rgb(255, 0, 255)),
-            ColorScheme::Retro => (Color::Rgb(64, 64, 64), Color::Rgb(0, 255, 0), Color::Rgb(255, 165, 0)),
-            ColorScheme::Desert => (Color::Rgb(210, 180, 140), Color::Black, Color::Rgb(255, 69, 0)),
-            ColorScheme::Space => (Color::Rgb(25, 25, 112), Color::White, Color::Rgb(255, 215, 0)),
-            ColorScheme::Candy => (Color::Rgb(255, 192, 203), Color::Black, Color::Rgb(127, 255, 212)),
-            ColorScheme::Earth => (Color::Rgb(139, 69, 19), Color::White, Color::Rgb(34, 139, 34)),
-            ColorScheme::Midnight => (Color::Rgb(25, 25, 112), Color::White, Color::Rgb(138, 43, 226)),
-            ColorScheme::Matrix => (Color::Black, Color::Rgb(0, 255, 0), Color::Rgb(0, 200, 0)),
-            ColorScheme::Nordic => (Color::Rgb(46, 52, 64), Color::Rgb(216, 222, 233), Color::Rgb(94, 129, 172)),
-            ColorScheme::Dracula => (Color::Rgb(40, 42, 54), Color::Rgb(248, 248, 242), Color::Rgb(255, 121, 198)),
-            ColorScheme::Solarized => (Color::Rgb(0, 43, 54), Color::Rgb(131, 148, 150), Color::Rgb(181, 137, 0)),
-            ColorScheme::Monokai => (Color::Rgb(39, 40, 34), Color::Rgb(248, 248, 242), Color::Rgb(249, 38, 114)),
-            ColorScheme::Gruvbox => (Color::Rgb(40, 40, 40), Color::Rgb(235, 219, 178), Color::Rgb(251, 73, 52)),
-            ColorScheme::Tokyo => (Color::Rgb(26, 27, 38), Color::Rgb(169, 177, 214), Color::Rgb(187, 154, 247)),
-            ColorScheme::Synthwave => (Color::Rgb(39, 23, 64), Color::Rgb(255, 236, 255), Color::Rgb(255, 82, 197)),
-            ColorScheme::Coffee => (Color::Rgb(59, 35, 20), Color::Rgb(237, 221, 185), Color::Rgb(191, 128, 64)),
-            ColorScheme::Nature => (Color::Rgb(42, 61, 44), Color::Rgb(233, 237, 201), Color::Rgb(139, 189, 139)),
+/// A program entry in config.toml: either `Name = "path"` or a table with metadata.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ProgramEntry {
+    Path(String),
+    Detailed(ProgramSpec),
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProgramSpec {
+    script: String,
+    description: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    distros: Vec<String>,
+    #[serde(default)]
+    requires_root: bool,
+    #[serde(default)]
+    risk: RiskLevel,
+    #[serde(default)]
+    untested: bool,
+    #[serde(default)]
+    aliases: Vec<String>,
+    #[serde(default)]
+    params: Vec<params::ParamSpec>,
+    sha256: Option<String>,
+}
+
+impl Program {
+    fn from_entry(category: &str, name: &str, entry: ProgramEntry, source: &config::CatalogLayer) -> Result<Self, String> {
+        let spec = match entry {
+            ProgramEntry::Path(script) => ProgramSpec {
+                script,
+                description: None,
+                tags: Vec::new(),
+                distros: Vec::new(),
+                requires_root: false,
+                risk: RiskLevel::default(),
+                untested: false,
+                aliases: Vec::new(),
+                params: Vec::new(),
+                sha256: None,
+            },
+            ProgramEntry::Detailed(spec) => spec,
+        };
+        let params = spec.params.into_iter().map(params::Param::from_spec).collect::<Result<Vec<_>, _>>()?;
+        if let Some(param) = params.iter().find(|param| params.iter().filter(|p| p.name == param.name).count() > 1) {
+            return Err(format!("parameter '{}' is declared twice", param.name));
         }
+        // Script paths are relative to the config file that names them
+        let config_dir = source.path.parent().unwrap_or(std::path::Path::new("."));
+
+        Ok(Program {
+            name: name.to_string(),
+            category: category.to_string(),
+            script: config_dir.join(spec.script),
+            is_favorite: false,
+            description: spec.description,
+            tags: spec.tags,
+            distros: spec.distros,
+            requires_root: spec.requires_root,
+            risk: spec.risk,
+            untested: spec.untested,
+            aliases: spec.aliases,
+            params,
+            sha256: spec.sha256,
+            locked_sha256: None,
+            locked_sources: Default::default(),
+            source: source.clone(),
+        })
     }
 
-    fn display_name(&self) -> &str {
-        match self {
-            ColorScheme::Default => "Default",
-            ColorScheme::Dark => "Dark",
-            ColorScheme::Light => "Light",
-            ColorScheme::Ocean => "Ocean",
-            ColorScheme::Forest => "Forest",
-            ColorScheme::Sunset => "Sunset",
-            ColorScheme::Neon => "Neon",
-            ColorScheme::Pastel => "Pastel",
-            ColorScheme::Monochrome => "Monochrome",
-            ColorScheme::Autumn => "Autumn",
-            ColorScheme::Winter => "Winter",
-            ColorScheme::Spring => "Spring",
-            ColorScheme::Summer => "Summer",
-            ColorScheme::Cyberpunk => "Cyberpunk",
-            ColorScheme::Retro => "Retro",
-            ColorScheme::Desert => "Desert",
-            ColorScheme::Space => "Space",
-            ColorScheme::Candy => "Candy",
-            ColorScheme::Earth => "Earth",
-            ColorScheme::Midnight => "Midnight",
-            ColorScheme::Matrix => "Matrix",
-            ColorScheme::Nordic => "Nordic",
-            ColorScheme::Dracula => "Dracula",
-            ColorScheme::Solarized => "Solarized",
-            ColorScheme::Monokai => "Monokai",
-            ColorScheme::Gruvbox => "Gruvbox",
-            ColorScheme::Tokyo => "Tokyo Night",
-            ColorScheme::Synthwave => "Synthwave",
-            ColorScheme::Coffee => "Coffee",
-            ColorScheme::Nature => "Nature",
+    /// Stable identifier used to persist per-program state.
+    fn key(&self) -> String {
+        format!("{}/{}", self.category, self.name)
+    }
+}
+
+/// A named, ordered set of catalog programs from `[profiles]`, e.g. everything a gaming desktop needs.
+#[derive(Clone)]
+struct Profile {
+    name: String,
+    description: Option<String>,
+    /// `Category/Program` keys, in the order they run
+    programs: Vec<String>,
+}
+
+/// A profile entry in config.toml: either `name = ["Category/Program", ...]` or a table with metadata.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ProfileEntry {
+    Programs(Vec<String>),
+    Detailed(ProfileSpec),
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProfileSpec {
+    programs: Vec<String>,
+    description: Option<String>,
+}
+
+impl Profile {
+    fn from_entry(name: &str, entry: ProfileEntry) -> Self {
+        let (programs, description) = match entry {
+            ProfileEntry::Programs(programs) => (programs, None),
+            ProfileEntry::Detailed(spec) => (spec.programs, spec.description),
+        };
+        Profile { name: name.to_string(), description, programs }
+    }
+
+    /// The catalog programs this profile runs, in order, and the keys that name no program.
+    fn resolve<'a>(&'a self, categories: &'a [Category]) -> (Vec<&'a Program>, Vec<&'a str>) {
+        let mut found = Vec::new();
+        let mut missing = Vec::new();
+        for key in &self.programs {
+            match find_program_by_key(categories, key) {
+                Some(program) => found.push(program),
+                None => missing.push(key.as_str()),
+            }
         }
+        (found, missing)
     }
 }
 
+#[derive(Clone, Copy, PartialEq)]
+enum MenuState {
+    Categories,
+    Programs,
+    Search,
+    Help,
+    SystemInfo,
+    History,
+    Output,
+    Doctor,
+    Preview,
+    Themes,
+    Queue,
+    Profiles,
+}
+
 struct AppState {
     status_message: Option<String>,
-    loading: bool,
-    loading_progress: u8,
     system_info: String,
     current_quote: String,
+    history: Vec<state::RunRecord>,
+    history_selected: usize,
+    session: Option<session::ScriptSession>,
+    output_view: Option<output_view::OutputView>,
+    doctor_findings: Vec<doctor::Finding>,
+    doctor_selected: usize,
+    preview: Option<preview::ScriptPreview>,
+    /// Screen to go back to when the preview is closed or its script started
+    preview_return: MenuState,
+    pending_run: Option<PendingRun>,
+    /// Key of the program being launched after its checksum warning was accepted
+    allowed_modified: Option<String>,
+    risk_confirm: Option<RiskConfirm>,
+    /// Values being entered for a program's parameters before it starts
+    param_form: Option<params::ParamForm>,
+    /// Password modal before the first program that needs root
+    sudo_prompt: Option<privilege::SudoPrompt>,
+    sudo_settings: settings::SudoSettings,
+    /// The toolbox was started as root, shown in the title bar
+    running_as_root: bool,
+    /// Cursor in the theme gallery; the theme under it is previewed but not applied
+    theme_selected: usize,
+    keymap: keymap::Keymap,
+    /// The search box greps script files instead of matching names
+    search_contents: bool,
+    /// Keys of the programs marked for a queued run, in the order they were marked
+    marked: Vec<String>,
+    queue: Option<queue::RunQueue>,
+    queue_on_failure: settings::OnFailure,
+    profiles: Vec<Profile>,
+    profile_selected: usize,
+}
+
+/// A run waiting for the user to accept a script that no longer matches its pinned checksum.
+struct PendingRun {
+    program: Program,
+    /// The script or the sourced file that changed
+    file: PathBuf,
+    expected: String,
+    actual: String,
+}
+
+/// A destructive program waiting for the user to type its name.
+struct RiskConfirm {
+    program: Program,
+    /// Asked for when a queue starts rather than when the program itself does
+    queued: bool,
+    typed: String,
+    error: Option<String>,
+}
+
+impl RiskConfirm {
+    fn new(program: Program, queued: bool) -> Self {
+        RiskConfirm { program, queued, typed: String::new(), error: None }
+    }
 }
 
 enum InputAction {
     Quit,
     RunScript,
+    PreviewScript,
+    RunQueue,
+    RunProfile,
+    ContinueQueue,
+    InstallUpdate,
     Continue,
     ConfirmQuit,
 }
@@ -231,63 +359,216 @@ fn setup_terminal() -> Result<Terminal<CrosstermBackend<Stdout>>, Box<dyn std::e
     Ok(terminal)
 }
 
-fn load_config(config_path: &PathBuf) -> Result<(Vec<Category>, PathBuf), Box<dyn std::error::Error>> {
-    let config = fs::read_to_string(config_path)?;
+fn load_config(layer: &config::CatalogLayer) -> Result<(Vec<Category>, Vec<Profile>), Box<dyn std::error::Error>> {
+    let config = fs::read_to_string(&layer.path)?;
     let config: Value = toml::from_str(&config)?;
 
-    let default_dir = PathBuf::from(".");
-    let config_dir = config_path.parent().unwrap_or(&default_dir);
+    let lock = integrity::load_lock(layer).map_err(|e| format!("{}: {}", integrity::LOCK_FILE, e))?;
 
     let mut categories = Vec::new();
-    for (category_name, category_value) in config.as_table().unwrap() {
+    let mut profiles = Vec::new();
+    let table = config.as_table().ok_or("config root must be a table")?;
+    for (category_name, category_value) in table {
+        if category_name == PROFILES_TABLE {
+            profiles.extend(parse_profiles(category_value)?);
+            continue;
+        }
+        let category_table = category_value
+            .as_table()
+            .ok_or_else(|| format!("[{}] must be a table of programs", category_name))?;
         let mut programs = Vec::new();
-        for (program_name, program_value) in category_value.as_table().unwrap() {
-            let script_path = config_dir.join(program_value.as_str().unwrap());
-            programs.push(Program {
-                name: program_name.to_string(),
-                script: script_path,
-                is_favorite: false,
-            });
+        for (program_name, program_value) in category_table {
+            let entry: ProgramEntry = program_value.clone().try_into().map_err(|e| {
+                format!("{}.{}: expected a script path or a table with `script`: {}", category_name, program_name, e)
+            })?;
+            let mut program = Program::from_entry(category_name, program_name, entry, layer)
+                .map_err(|e| format!("{}.{}: {}", category_name, program_name, e))?;
+            if let Some(entry) = lock.programs.get(&program.key()) {
+                program.locked_sha256 = Some(entry.sha256.clone());
+                program.locked_sources = entry.sources.clone();
+            }
+            programs.push(program);
         }
         categories.push(Category {
             name: category_name.to_string(),
             programs,
+            kind: CategoryKind::Catalog,
         });
     }
 
-    Ok((categories, config_dir.to_path_buf()))
+    Ok((categories, profiles))
 }
 
-fn check_for_updates() -> Result<Option<String>, Box<dyn std::error::Error>> {
-    println!("Checking for updates...");
-    println!("Current version: {}", CURRENT_VERSION);
-    
-    let current_version = Version::parse(CURRENT_VERSION)
-        .map_err(|e| format!("Failed to parse current version '{}': {}", CURRENT_VERSION, e))?;
-    
-    println!("Parsed current version: {}", current_version);
-    
-    let client = Client::new();
-    let url = format!("https://api.github.com/repos/{}/releases/latest", GITHUB_REPO);
-    let response = client.get(&url).header("User-Agent", "LinuxToolbox").send()?;
-    
-    if response.status().is_success() {
-        let json: JsonValue = response.json()?;
-        if let Some(tag_name) = json["tag_name"].as_str() {
-            println!("Latest version from GitHub: {}", tag_name);
-            
-            let latest_version = Version::parse(&tag_name.trim_start_matches('v'))
-                .map_err(|e| format!("Failed to parse latest version '{}': {}", tag_name, e))?;
-            
-            println!("Parsed latest version: {}", latest_version);
-            
-            if latest_version > current_version {
-                return Ok(Some(latest_version.to_string()));
+/// Reads the reserved `[profiles]` table. Entries shaped like programs are refused rather than
+/// taken for a category that happens to be called profiles.
+fn parse_profiles(value: &Value) -> Result<Vec<Profile>, String> {
+    let profile_table = value.as_table().ok_or_else(|| format!("[{}] must be a table of profiles", PROFILES_TABLE))?;
+    let mut profiles = Vec::new();
+    for (profile_name, profile_value) in profile_table {
+        let looks_like_program = profile_value.is_str() || profile_value.as_table().is_some_and(|table| table.contains_key("script"));
+        if looks_like_program {
+            return Err(format!(
+                "{}.{} looks like a program, but [{}] is reserved for profiles; put programs in another category",
+                PROFILES_TABLE, profile_name, PROFILES_TABLE
+            ));
+        }
+        let entry: ProfileEntry = profile_value.clone().try_into().map_err(|e| {
+            format!("{}.{}: expected a list of Category/Program names or a table with `programs`: {}", PROFILES_TABLE, profile_name, e)
+        })?;
+        profiles.push(Profile::from_entry(profile_name, entry));
+    }
+    Ok(profiles)
+}
+
+/// Loads every layer and merges them. A program or profile defined again in a later layer replaces
+/// the earlier definition; new categories, programs and profiles are added alongside the existing ones.
+fn load_catalog(layers: &[config::CatalogLayer]) -> Result<(Vec<Category>, Vec<Profile>), Box<dyn std::error::Error>> {
+    let mut categories: Vec<Category> = Vec::new();
+    let mut profiles: Vec<Profile> = Vec::new();
+    for layer in layers {
+        let (layer_categories, layer_profiles) = load_config(layer).map_err(|e| format!("{}: {}", layer.path.display(), e))?;
+        logging::debug("Loaded catalog layer", json!({
+            "source": layer.source.display_name(),
+            "path": layer.path.display().to_string(),
+            "categories": layer_categories.len(),
+            "profiles": layer_profiles.len(),
+        }));
+        for profile in layer_profiles {
+            match profiles.iter_mut().find(|p| p.name == profile.name) {
+                Some(slot) => *slot = profile,
+                None => profiles.push(profile),
+            }
+        }
+        for category in layer_categories {
+            let Some(existing) = categories.iter_mut().find(|c| c.name == category.name) else {
+                categories.push(category);
+                continue;
+            };
+            for program in category.programs {
+                match existing.programs.iter_mut().find(|p| p.name == program.name) {
+                    Some(slot) => *slot = program,
+                    None => existing.programs.push(program),
+                }
             }
         }
     }
-    
-    Ok(None)
+
+    categories.sort_by(|a, b| a.name.cmp(&b.name));
+    for category in &mut categories {
+        category.programs.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+    embedded::resolve_missing_scripts(&mut categories);
+
+    profiles.sort_by(|a, b| a.name.cmp(&b.name));
+    for profile in &profiles {
+        let (_, missing) = profile.resolve(&categories);
+        if !missing.is_empty() {
+            logging::warn("Profile names unknown programs", json!({ "profile": profile.name, "missing": missing }));
+        }
+    }
+
+    Ok((categories, profiles))
+}
+
+fn apply_favorites(categories: &mut [Category], favorites: &HashSet<String>) {
+    for program in categories.iter_mut().flat_map(|c| c.programs.iter_mut()) {
+        program.is_favorite = favorites.contains(&program.key());
+    }
+}
+
+/// Rebuilds the pinned Favorites category from the catalog's `is_favorite` flags.
+fn refresh_favorites_category(categories: &mut Vec<Category>) {
+    categories.retain(|c| c.kind != CategoryKind::Favorites);
+
+    let favorites: Vec<Program> = categories
+        .iter()
+        .flat_map(|c| c.programs.iter())
+        .filter(|p| p.is_favorite)
+        .cloned()
+        .collect();
+    if !favorites.is_empty() {
+        categories.insert(0, Category {
+            name: FAVORITES_CATEGORY.to_string(),
+            programs: favorites,
+            kind: CategoryKind::Favorites,
+        });
+    }
+}
+
+/// Rebuilds the pinned Recent category, below Favorites, from the run history.
+fn refresh_recent_category(categories: &mut Vec<Category>, history: &[state::RunRecord]) {
+    categories.retain(|c| c.kind != CategoryKind::Recent);
+
+    let mut recent: Vec<Program> = Vec::new();
+    for record in history {
+        if recent.len() == RECENT_LIMIT {
+            break;
+        }
+        if recent.iter().any(|p| p.key() == record.key) {
+            continue;
+        }
+        if let Some(program) = find_program_by_key(categories, &record.key) {
+            recent.push(program.clone());
+        }
+    }
+
+    if !recent.is_empty() {
+        let index = categories.iter().filter(|c| c.kind == CategoryKind::Favorites).count();
+        categories.insert(index, Category {
+            name: RECENT_CATEGORY.to_string(),
+            programs: recent,
+            kind: CategoryKind::Recent,
+        });
+    }
+}
+
+fn find_program_by_key<'a>(categories: &'a [Category], key: &str) -> Option<&'a Program> {
+    categories
+        .iter()
+        .filter(|c| c.kind == CategoryKind::Catalog)
+        .flat_map(|c| c.programs.iter())
+        .find(|p| p.key() == key)
+}
+
+fn toggle_favorite(categories: &mut Vec<Category>, key: &str) -> bool {
+    let mut is_favorite = false;
+    // Pseudo-categories hold copies, so flip every copy to keep the ★ in sync
+    for program in categories
+        .iter_mut()
+        .flat_map(|c| c.programs.iter_mut())
+        .filter(|p| p.key() == key)
+    {
+        program.is_favorite = !program.is_favorite;
+        is_favorite = program.is_favorite;
+    }
+    refresh_favorites_category(categories);
+
+    let favorites: Vec<String> = categories
+        .iter()
+        .filter(|c| c.kind == CategoryKind::Favorites)
+        .flat_map(|c| c.programs.iter().map(|p| p.key()))
+        .collect();
+    if let Err(e) = state::save_favorites(&favorites) {
+        logging::error("Failed to save favorites", json!({ "error": e.to_string() }));
+    }
+
+    is_favorite
+}
+
+/// Adds the program to the end of the run queue, or takes it off if it is already marked.
+fn toggle_mark(app_state: &mut AppState, program: &Program) {
+    let key = program.key();
+    let verb = match app_state.marked.iter().position(|marked| *marked == key) {
+        Some(index) => {
+            app_state.marked.remove(index);
+            "Unmarked"
+        }
+        None => {
+            app_state.marked.push(key);
+            "Marked"
+        }
+    };
+    app_state.status_message = Some(format!("{} {} ({} marked)", verb, program.name, app_state.marked.len()));
 }
 
 fn check_script(script: &PathBuf) -> std::io::Result<()> {
@@ -317,297 +598,673 @@ fn check_script(script: &PathBuf) -> std::io::Result<()> {
     Ok(())
 }
 
-fn run_script(script: &PathBuf) -> std::io::Result<()> {
+/// Runs a script attached to the toolbox's terminal, copying what it prints to `output`.
+fn execute_script(script: &PathBuf, output: Option<&Path>, invocation: &params::Invocation) -> std::io::Result<ExitStatus> {
     check_script(script)?;
+    session::run_attached(script, invocation, output)
+}
 
-    disable_raw_mode()?;
-    execute!(stdout(), LeaveAlternateScreen, Show)?;
-    execute!(stdout(), crossterm::terminal::Clear(crossterm::terminal::ClearType::All), MoveTo(0, 0))?;
-
-    let status = Command::new("bash")
-        .arg("-c")
-        .arg(script.to_str().unwrap())
-        .status()?;
-
-    if !status.success() {
-        println!("Script exited with non-zero status code");
+/// Runs a program's script and appends the outcome, including launch failures, to the run history.
+fn execute_and_record(program: &Program, invocation: &params::Invocation) -> std::io::Result<ExitStatus> {
+    let started_at = Local::now();
+    let output = state::new_output_path(&program.key(), started_at).ok();
+    let result = execute_script(&program.script, output.as_deref(), invocation);
+    let output = output.filter(|path| path.exists());
+    match &result {
+        Ok(status) => record_run(program, started_at, status.code(), status.success(), None, output),
+        Err(e) => record_run(program, started_at, None, false, Some(e.to_string()), output),
     }
+    result
+}
 
-    println!("Press any key to continue...");
-    let _ = event::read()?;
-
-    Ok(())
+fn record_run(program: &Program, started_at: DateTime<Local>, exit_code: Option<i32>, success: bool, error: Option<String>, output: Option<PathBuf>) {
+    let finished_at = Local::now();
+
+    let record = state::RunRecord {
+        key: program.key(),
+        category: program.category.clone(),
+        program: program.name.clone(),
+        script: program.script.clone(),
+        started_at,
+        finished_at,
+        duration_ms: (finished_at - started_at).num_milliseconds(),
+        exit_code,
+        success,
+        error,
+        output,
+    };
+    if let Err(e) = state::append_history(&record) {
+        logging::error("Failed to record run history", json!({ "error": e.to_string() }));
+    }
 }
 
-fn draw_help_screen<B: Backend>(f: &mut Frame<B>, color_scheme: &ColorScheme) {
-    let (bg_color, fg_color, _highlight_color) = color_scheme.get_colors();
+fn draw_help_screen<B: Backend>(f: &mut Frame<B>, theme: &theme::Theme, keys: &keymap::Keymap) {
+    use keymap::Screen;
+    let key = |screen: Screen, action: Action| {
+        let bound = keys.keys(screen, action);
+        if bound.is_empty() { "(unbound)".to_string() } else { bound }
+    };
     let help_text = vec![
         Line::from("Linux Toolbox Help"),
         Line::from(""),
         Line::from("Navigation:"),
-        Line::from("↑↓ or Mouse Wheel: Move selection"),
-        Line::from("Mouse Click or Enter: Select/Run program"),
-        Line::from("Esc/Backspace: Go back"),
+        Line::from(format!("{}/{} or Mouse Wheel: Move selection", key(Screen::Categories, Action::Up), key(Screen::Categories, Action::Down))),
+        Line::from(format!("Click: Select program, Double click or {}: Run it", key(Screen::Programs, Action::Select))),
+        Line::from(format!("{}: Go back", key(Screen::Programs, Action::Back))),
         Line::from(""),
         Line::from("Shortcuts:"),
-        Line::from("/: Search"),
-        Line::from("Tab: Change color scheme"),
-        Line::from("h: Toggle help screen"),
-        Line::from("q: Quit"),
+        Line::from(format!("{}: Search", key(Screen::Categories, Action::Search))),
+        Line::from(format!("{}: Change color scheme", key(Screen::Global, Action::NextTheme))),
+        Line::from(format!("{}: Theme gallery (previews each theme, {} applies it)", key(Screen::Global, Action::Themes), key(Screen::Themes, Action::Select))),
+        Line::from(format!("{}: Toggle help screen", key(Screen::Global, Action::Help))),
+        Line::from(format!("{}: Quit", key(Screen::Global, Action::Quit))),
         Line::from("1-9: Quick select category"),
-        Line::from("Home: Back to top"),
-        Line::from("f: Toggle favorite"),
-        Line::from("i: View system information"),
+        Line::from(format!("{}: Back to top", key(Screen::Categories, Action::Top))),
+        Line::from(format!("{}: Toggle favorite", key(Screen::Programs, Action::Favorite))),
+        Line::from(format!("{}: Profiles ({} runs every program of the selected profile in order)", key(Screen::Global, Action::Profiles), key(Screen::Profiles, Action::Select))),
+        Line::from(format!(
+            "{}: Mark for a queued run ({} in search), {} runs the marked programs in order",
+            key(Screen::Programs, Action::Mark),
+            key(Screen::Search, Action::Mark),
+            key(Screen::Programs, Action::RunQueue),
+        )),
+        Line::from(format!("{}: Preview the selected script's source (also shown before a program's first run)", key(Screen::Programs, Action::Preview))),
+        Line::from(format!("{}: View system information", key(Screen::Global, Action::SystemInfo))),
+        Line::from(format!(
+            "{}: Run history ({} re-runs the selected entry, {} views its output)",
+            key(Screen::Global, Action::History),
+            key(Screen::History, Action::Select),
+            key(Screen::History, Action::Output),
+        )),
+        Line::from(format!("{}: Install the available update", key(Screen::Global, Action::InstallUpdate))),
+        Line::from(format!("{}: Doctor (checks every script, {} applies the suggested fix)", key(Screen::Global, Action::Doctor), key(Screen::Doctor, Action::Fix))),
+        Line::from(format!("Key bindings can be changed in {}", keymap::keymap_path().display())),
+        Line::from(""),
+        Line::from("While a script runs:"),
+        Line::from("Keys are sent to the script, Shift+PgUp/PgDn or Mouse Wheel scrolls"),
+        Line::from("Enter/Esc closes the output once the script has finished"),
+        Line::from(""),
+        Line::from(keys.hints(Screen::Help, &[(&[Action::Help, Action::Back], "Return to the main menu")])),
     ];
 
     let help_paragraph = Paragraph::new(help_text)
         .block(Block::default().title("Help").borders(Borders::ALL))
-        .style(Style::default().fg(fg_color).bg(bg_color));
+        .style(theme.text());
 
     f.render_widget(help_paragraph, f.size());
 }
 
-fn handle_input<'a>(
+/// The question shown after the quit key, answered with the yes key.
+fn quit_prompt(keys: &keymap::Keymap) -> String {
+    format!("Press {} to confirm quit, any other key to cancel", keys.keys(keymap::Screen::Global, Action::Yes))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn handle_input(
     key: KeyEvent,
     menu_state: &mut MenuState,
     selected_category: &mut usize,
     selected_program: &mut usize,
-    categories: &'a mut [Category],
+    categories: &mut Vec<Category>,
     search_query: &mut String,
-    filtered_programs: &mut Vec<(String, String, PathBuf)>,
+    filtered_programs: &mut Vec<search::SearchResult>,
     category_state: &mut ListState,
     program_state: &mut ListState,
-    color_scheme: &mut ColorScheme,
+    themes: &mut theme::ThemeSet,
     app_state: &mut AppState,
 ) -> (InputAction, bool) {
     let mut menu_state_changed = false;
-    
-    match key.code {
-        KeyCode::Tab => {
-            *color_scheme = color_scheme.next();
-            if let Err(e) = save_color_scheme(color_scheme) {
-                eprintln!("Failed to save color scheme: {}", e);
+
+    // The output viewer has its own search box, so no global shortcut may steal its keys
+    if *menu_state == MenuState::Output {
+        let close = app_state.output_view.as_mut().is_none_or(|view| view.handle_key(key));
+        if close {
+            app_state.output_view = None;
+            *menu_state = MenuState::History;
+            menu_state_changed = true;
+        }
+        return (InputAction::Continue, menu_state_changed);
+    }
+
+    if app_state.keymap.action(keymap::Screen::Global, &key) == Some(Action::Yes) && app_state.status_message == Some(quit_prompt(&app_state.keymap)) {
+        return (InputAction::Quit, menu_state_changed);
+    }
+
+    let Some(action) = app_state.keymap.action(key_screen(*menu_state), &key) else {
+        // Keys without a binding: typing into the search box, quick select and preview scrolling
+        match menu_state {
+            MenuState::Search => match key.code {
+                KeyCode::Char(c) => {
+                    search_query.push(c);
+                    update_filtered_programs(categories, search_query, app_state.search_contents, filtered_programs, selected_program, program_state);
+                }
+                KeyCode::Backspace => {
+                    search_query.pop();
+                    update_filtered_programs(categories, search_query, app_state.search_contents, filtered_programs, selected_program, program_state);
+                }
+                _ => {}
+            },
+            MenuState::Categories => {
+                if let KeyCode::Char(c) = key.code {
+                    let index = c.to_digit(10).unwrap_or(0) as usize;
+                    if index > 0 && index <= categories.len() {
+                        *selected_category = index - 1;
+                        category_state.select(Some(*selected_category));
+                    }
+                }
+            }
+            MenuState::Preview if app_state.preview.as_mut().is_none_or(|preview| preview.handle_key(key)) => {
+                app_state.preview = None;
+                *menu_state = app_state.preview_return;
+                menu_state_changed = true;
+            }
+            _ => {}
+        }
+        return (InputAction::Continue, menu_state_changed);
+    };
+
+    match action {
+        Action::NextTheme => {
+            themes.next();
+            if let Err(e) = themes.save() {
+                logging::error("Failed to save theme", json!({ "error": e.to_string() }));
             }
             (InputAction::Continue, menu_state_changed)
         },
-        KeyCode::Char('q') => {
-            app_state.status_message = Some("Press 'y' to confirm quit, any other key to cancel".to_string());
+        Action::Quit => {
+            app_state.status_message = Some(quit_prompt(&app_state.keymap));
             (InputAction::ConfirmQuit, menu_state_changed)
         },
-        KeyCode::Char('y') if app_state.status_message == Some("Press 'y' to confirm quit, any other key to cancel".to_string()) => {
-            (InputAction::Quit, menu_state_changed)
-        },
-        KeyCode::Char('h') => {
+        Action::Help => {
             let new_state = if *menu_state == MenuState::Help { MenuState::Categories } else { MenuState::Help };
             menu_state_changed = *menu_state != new_state;
             *menu_state = new_state;
             (InputAction::Continue, menu_state_changed)
         },
-        KeyCode::Char('f') if *menu_state == MenuState::Programs => {
-            let program = &mut categories[*selected_category].programs[*selected_program];
-            program.is_favorite = !program.is_favorite;
-            app_state.status_message = Some(format!("{} {} favorites", if program.is_favorite { "Added to" } else { "Removed from" }, program.name));
+        Action::SystemInfo => {
+            let new_state = if *menu_state == MenuState::SystemInfo { MenuState::Categories } else { MenuState::SystemInfo };
+            menu_state_changed = *menu_state != new_state;
+            *menu_state = new_state;
+            (InputAction::Continue, menu_state_changed)
+        },
+        Action::History => {
+            let new_state = if *menu_state == MenuState::History { MenuState::Categories } else { MenuState::History };
+            menu_state_changed = *menu_state != new_state;
+            *menu_state = new_state;
+            app_state.history_selected = 0;
             (InputAction::Continue, menu_state_changed)
         },
-        KeyCode::Char('i') => {
-            let new_state = if *menu_state == MenuState::SystemInfo { MenuState::Categories } else { MenuState::SystemInfo };
+        Action::InstallUpdate => (InputAction::InstallUpdate, menu_state_changed),
+        Action::Profiles => {
+            let new_state = if *menu_state == MenuState::Profiles { MenuState::Categories } else { MenuState::Profiles };
+            menu_state_changed = *menu_state != new_state;
+            *menu_state = new_state;
+            (InputAction::Continue, menu_state_changed)
+        },
+        Action::Themes => {
+            let new_state = if *menu_state == MenuState::Themes { MenuState::Categories } else { MenuState::Themes };
+            if new_state == MenuState::Themes {
+                app_state.theme_selected = themes.current_index();
+            }
+            menu_state_changed = *menu_state != new_state;
+            *menu_state = new_state;
+            (InputAction::Continue, menu_state_changed)
+        },
+        Action::Doctor => {
+            let new_state = if *menu_state == MenuState::Doctor { MenuState::Categories } else { MenuState::Doctor };
+            if new_state == MenuState::Doctor {
+                app_state.doctor_findings = doctor::diagnose(categories);
+                app_state.doctor_selected = 0;
+                app_state.status_message = None;
+            }
             menu_state_changed = *menu_state != new_state;
             *menu_state = new_state;
             (InputAction::Continue, menu_state_changed)
         },
         _ => match menu_state {
-            MenuState::Categories => match key.code {
-                KeyCode::Char('/') => {
+            MenuState::Categories => match action {
+                Action::Search => {
                     *menu_state = MenuState::Search;
                     search_query.clear();
+                    app_state.search_contents = false;
+                    update_filtered_programs(categories, search_query, app_state.search_contents, filtered_programs, selected_program, program_state);
                     (InputAction::Continue, menu_state_changed)
                 }
-                KeyCode::Up => {
+                Action::Up => {
                     if *selected_category > 0 {
                         *selected_category -= 1;
                         category_state.select(Some(*selected_category));
                     }
                     (InputAction::Continue, menu_state_changed)
                 }
-                KeyCode::Down => {
+                Action::Down => {
                     if *selected_category < categories.len() - 1 {
                         *selected_category += 1;
                         category_state.select(Some(*selected_category));
                     }
                     (InputAction::Continue, menu_state_changed)
                 }
-                KeyCode::Enter => {
+                Action::Select => {
                     *menu_state = MenuState::Programs;
                     *selected_program = 0;
                     program_state.select(Some(0));
                     (InputAction::Continue, menu_state_changed)
                 }
-                KeyCode::Home => {
+                Action::Top => {
                     *selected_category = 0;
                     category_state.select(Some(0));
                     (InputAction::Continue, menu_state_changed)
                 }
-                KeyCode::Char(c) if c.is_digit(10) => {
-                    let index = c.to_digit(10).unwrap() as usize;
-                    if index > 0 && index <= categories.len() {
-                        *selected_category = index - 1;
-                        category_state.select(Some(*selected_category));
-                    }
-                    (InputAction::Continue, menu_state_changed)
-                }
+                Action::RunQueue => (InputAction::RunQueue, menu_state_changed),
                 _ => (InputAction::Continue, menu_state_changed),
             },
-            MenuState::Programs => match key.code {
-                KeyCode::Char('/') => {
+            MenuState::Programs => match action {
+                Action::Search => {
                     *menu_state = MenuState::Search;
                     search_query.clear();
+                    app_state.search_contents = false;
+                    update_filtered_programs(categories, search_query, app_state.search_contents, filtered_programs, selected_program, program_state);
                     (InputAction::Continue, menu_state_changed)
                 }
-                KeyCode::Up => {
+                Action::Up => {
                     if *selected_program > 0 {
                         *selected_program -= 1;
                         program_state.select(Some(*selected_program));
                     }
                     (InputAction::Continue, menu_state_changed)
                 }
-                KeyCode::Down => {
+                Action::Down => {
                     if *selected_program < categories[*selected_category].programs.len() - 1 {
                         *selected_program += 1;
                         program_state.select(Some(*selected_program));
                     }
                     (InputAction::Continue, menu_state_changed)
                 }
-                KeyCode::Enter => (InputAction::RunScript, menu_state_changed),
-                KeyCode::Esc | KeyCode::Backspace => {
+                Action::Select => (InputAction::RunScript, menu_state_changed),
+                Action::Preview => (InputAction::PreviewScript, menu_state_changed),
+                Action::Favorite => {
+                    let program = &categories[*selected_category].programs[*selected_program];
+                    let (key, name) = (program.key(), program.name.clone());
+                    let category_name = categories[*selected_category].name.clone();
+
+                    let is_favorite = toggle_favorite(categories, &key);
+                    app_state.status_message = Some(format!("{} {} favorites", if is_favorite { "Added to" } else { "Removed from" }, name));
+
+                    // The Favorites category may have appeared or vanished, so follow the selection by name
+                    match categories.iter().position(|c| c.name == category_name) {
+                        Some(index) => {
+                            *selected_category = index;
+                            *selected_program = (*selected_program).min(categories[index].programs.len() - 1);
+                        }
+                        None => {
+                            *selected_category = 0;
+                            *selected_program = 0;
+                            *menu_state = MenuState::Categories;
+                            menu_state_changed = true;
+                        }
+                    }
+                    category_state.select(Some(*selected_category));
+                    program_state.select(Some(*selected_program));
+                    (InputAction::Continue, menu_state_changed)
+                }
+                Action::Back => {
                     *menu_state = MenuState::Categories;
                     *selected_program = 0;
                     program_state.select(Some(0));
                     (InputAction::Continue, menu_state_changed)
                 }
-                KeyCode::Home => {
+                Action::Top => {
                     *selected_program = 0;
                     program_state.select(Some(0));
                     (InputAction::Continue, menu_state_changed)
                 }
+                Action::Mark => {
+                    let programs = &categories[*selected_category].programs;
+                    toggle_mark(app_state, &programs[*selected_program]);
+                    // Move on, so a run of programs can be marked by holding the key
+                    if *selected_program + 1 < programs.len() {
+                        *selected_program += 1;
+                        program_state.select(Some(*selected_program));
+                    }
+                    (InputAction::Continue, menu_state_changed)
+                }
+                Action::RunQueue => (InputAction::RunQueue, menu_state_changed),
                 _ => (InputAction::Continue, menu_state_changed),
             },
-            MenuState::Search => match key.code {
-                KeyCode::Enter => {
+            MenuState::Search => match action {
+                Action::Up => {
+                    *selected_program = selected_program.saturating_sub(1);
+                    program_state.select(Some(*selected_program));
+                    (InputAction::Continue, menu_state_changed)
+                }
+                Action::Down => {
+                    if *selected_program + 1 < filtered_programs.len() {
+                        *selected_program += 1;
+                        program_state.select(Some(*selected_program));
+                    }
+                    (InputAction::Continue, menu_state_changed)
+                }
+                Action::Select => {
                     if !filtered_programs.is_empty() {
                         (InputAction::RunScript, menu_state_changed)
                     } else {
                         (InputAction::Continue, menu_state_changed)
                     }
                 },
-                KeyCode::Esc => {
+                Action::Back => {
                     *menu_state = MenuState::Categories;
                     search_query.clear();
                     (InputAction::Continue, menu_state_changed)
                 }
-                KeyCode::Char(c) => {
-                    search_query.push(c);
-                    update_filtered_programs(categories, search_query, filtered_programs);
+                Action::SearchContents => {
+                    app_state.search_contents = !app_state.search_contents;
+                    update_filtered_programs(categories, search_query, app_state.search_contents, filtered_programs, selected_program, program_state);
                     (InputAction::Continue, menu_state_changed)
                 }
-                KeyCode::Backspace => {
-                    search_query.pop();
-                    update_filtered_programs(categories, search_query, filtered_programs);
+                Action::Mark => {
+                    let program = filtered_programs
+                        .get(*selected_program)
+                        .and_then(|result| find_program_by_key(categories, &format!("{}/{}", result.category, result.name)));
+                    if let Some(program) = program {
+                        toggle_mark(app_state, program);
+                    }
+                    if *selected_program + 1 < filtered_programs.len() {
+                        *selected_program += 1;
+                        program_state.select(Some(*selected_program));
+                    }
                     (InputAction::Continue, menu_state_changed)
                 }
+                Action::RunQueue => (InputAction::RunQueue, menu_state_changed),
                 _ => (InputAction::Continue, menu_state_changed),
             },
-            MenuState::Help => match key.code {
-                KeyCode::Esc | KeyCode::Char('h') => {
+            MenuState::Help | MenuState::SystemInfo => match action {
+                Action::Back => {
                     *menu_state = MenuState::Categories;
                     (InputAction::Continue, menu_state_changed)
                 }
                 _ => (InputAction::Continue, menu_state_changed),
             },
-            MenuState::SystemInfo => match key.code {
-                KeyCode::Esc | KeyCode::Char('i') => {
+            MenuState::History => match action {
+                Action::Up => {
+                    app_state.history_selected = app_state.history_selected.saturating_sub(1);
+                    (InputAction::Continue, menu_state_changed)
+                }
+                Action::Down => {
+                    if app_state.history_selected + 1 < app_state.history.len() {
+                        app_state.history_selected += 1;
+                    }
+                    (InputAction::Continue, menu_state_changed)
+                }
+                Action::Top => {
+                    app_state.history_selected = 0;
+                    (InputAction::Continue, menu_state_changed)
+                }
+                Action::Select if !app_state.history.is_empty() => (InputAction::RunScript, menu_state_changed),
+                Action::Preview if !app_state.history.is_empty() => (InputAction::PreviewScript, menu_state_changed),
+                Action::Output => {
+                    let Some(record) = app_state.history.get(app_state.history_selected) else {
+                        return (InputAction::Continue, menu_state_changed);
+                    };
+                    let title = format!("{} — {}", record.key, record.started_at.format("%Y-%m-%d %H:%M:%S"));
+                    match record.output.as_ref().map(|path| output_view::OutputView::open(title, path)) {
+                        Some(Ok(view)) => {
+                            app_state.output_view = Some(view);
+                            *menu_state = MenuState::Output;
+                            menu_state_changed = true;
+                        }
+                        Some(Err(e)) => app_state.status_message = Some(format!("Cannot open output: {}", e)),
+                        None => app_state.status_message = Some("No output was saved for this run".to_string()),
+                    }
+                    (InputAction::Continue, menu_state_changed)
+                }
+                Action::Back => {
                     *menu_state = MenuState::Categories;
                     (InputAction::Continue, menu_state_changed)
                 }
                 _ => (InputAction::Continue, menu_state_changed),
             },
-        },
-    }
-}
-
-fn update_filtered_programs(
-    categories: &[Category],
-    search_query: &str,
-    filtered_programs: &mut Vec<(String, String, PathBuf)>,
-) {
-    filtered_programs.clear();
-    for category in categories {
-        for program in &category.programs {
-            if program.name.to_lowercase().contains(&search_query.to_lowercase()) {
-                filtered_programs.push((category.name.clone(), program.name.clone(), program.script.clone()));
-            }
-        }
-    }
-}
-
-fn draw_ui<B: Backend>(
-    f: &mut Frame<B>,
-    categories: &[Category],
-    selected_category: usize,
-    category_state: &mut ListState,
-    program_state: &mut ListState,
-    menu_state: &MenuState,
-    search_query: &str,
-    filtered_programs: &[(String, String, PathBuf)],
-    update_available: &Option<String>,
-    color_scheme: &ColorScheme,
-    app_state: &AppState,
-) {
-    let (bg_color, fg_color, _highlight_color) = color_scheme.get_colors();
-
-    let size = f.size();
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(3),  // Title
-            Constraint::Length(3),  // Search bar
-            Constraint::Min(10),    // Main content (categories and programs)
-            Constraint::Length(3),  // OS info
-            Constraint::Length(3),  // Help text
-            Constraint::Length(3),  // Quote (new)
-        ].as_ref())
-        .split(size);
-
-    // Modified title section
-    let current_date = Local::now().format("%Y-%m-%d").to_string();
-    let mut title_text = vec![
-        Line::from(vec![
-            Span::styled("Linux Toolbox ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-            Span::styled(format!("v{}", CURRENT_VERSION), Style::default().fg(Color::Yellow)),
-            Span::raw(" by "),
-            Span::styled("Tech Logicals", Style::default().fg(Color::Green).add_modifier(Modifier::ITALIC)),
-            Span::raw(" | "),
-            Span::styled(current_date, Style::default().fg(Color::Magenta)),
-            Span::raw(" | Theme: "),
-            Span::styled(color_scheme.display_name(), Style::default().fg(Color::Cyan)),
-        ]),
-    ];
+            MenuState::Doctor => match action {
+                Action::Up => {
+                    app_state.doctor_selected = app_state.doctor_selected.saturating_sub(1);
+                    (InputAction::Continue, menu_state_changed)
+                }
+                Action::Down => {
+                    if app_state.doctor_selected + 1 < app_state.doctor_findings.len() {
+                        app_state.doctor_selected += 1;
+                    }
+                    (InputAction::Continue, menu_state_changed)
+                }
+                Action::Top => {
+                    app_state.doctor_selected = 0;
+                    (InputAction::Continue, menu_state_changed)
+                }
+                Action::Fix => {
+                    let Some(finding) = app_state.doctor_findings.get(app_state.doctor_selected) else {
+                        return (InputAction::Continue, menu_state_changed);
+                    };
+                    app_state.status_message = Some(match doctor::apply_fix(finding) {
+                        Ok(()) => {
+                            logging::info("Doctor fix applied", json!({ "path": finding.path, "fix": finding.fix }));
+                            format!("Fixed {}", finding.path.display())
+                        }
+                        Err(e) => format!("Cannot fix {}: {}", finding.path.display(), e),
+                    });
+                    app_state.doctor_findings = doctor::diagnose(categories);
+                    app_state.doctor_selected = app_state.doctor_selected.min(app_state.doctor_findings.len().saturating_sub(1));
+                    (InputAction::Continue, menu_state_changed)
+                }
+                Action::FixAll => {
+                    let fixable: Vec<_> = app_state.doctor_findings.iter().filter(|f| f.fix.is_some()).collect();
+                    let failed = fixable.iter().filter(|finding| doctor::apply_fix(finding).is_err()).count();
+                    app_state.status_message = Some(format!("Applied {} fixes, {} failed", fixable.len() - failed, failed));
+                    logging::info("Doctor fixes applied", json!({ "applied": fixable.len() - failed, "failed": failed }));
+                    app_state.doctor_findings = doctor::diagnose(categories);
+                    app_state.doctor_selected = 0;
+                    (InputAction::Continue, menu_state_changed)
+                }
+                Action::Back => {
+                    *menu_state = MenuState::Categories;
+                    (InputAction::Continue, menu_state_changed)
+                }
+                _ => (InputAction::Continue, menu_state_changed),
+            },
+            MenuState::Preview => match action {
+                Action::Select if app_state.preview.as_ref().is_some_and(|p| p.error.is_none()) => (InputAction::RunScript, menu_state_changed),
+                Action::Back => {
+                    app_state.preview = None;
+                    *menu_state = app_state.preview_return;
+                    menu_state_changed = true;
+                    (InputAction::Continue, menu_state_changed)
+                }
+                _ => (InputAction::Continue, menu_state_changed),
+            },
+            MenuState::Themes => match action {
+                Action::Up => {
+                    app_state.theme_selected = app_state.theme_selected.saturating_sub(1);
+                    (InputAction::Continue, menu_state_changed)
+                }
+                Action::Down => {
+                    if app_state.theme_selected + 1 < themes.themes().len() {
+                        app_state.theme_selected += 1;
+                    }
+                    (InputAction::Continue, menu_state_changed)
+                }
+                Action::Top => {
+                    app_state.theme_selected = 0;
+                    (InputAction::Continue, menu_state_changed)
+                }
+                Action::Bottom => {
+                    app_state.theme_selected = themes.themes().len() - 1;
+                    (InputAction::Continue, menu_state_changed)
+                }
+                Action::Select => {
+                    themes.set_current(app_state.theme_selected);
+                    if let Err(e) = themes.save() {
+                        logging::error("Failed to save theme", json!({ "error": e.to_string() }));
+                    }
+                    app_state.status_message = Some(format!("Theme set to {}", themes.current().name));
+                    *menu_state = MenuState::Categories;
+                    (InputAction::Continue, menu_state_changed)
+                }
+                Action::Back => {
+                    *menu_state = MenuState::Categories;
+                    (InputAction::Continue, menu_state_changed)
+                }
+                _ => (InputAction::Continue, menu_state_changed),
+            },
+            MenuState::Profiles => match action {
+                Action::Up => {
+                    app_state.profile_selected = app_state.profile_selected.saturating_sub(1);
+                    (InputAction::Continue, menu_state_changed)
+                }
+                Action::Down => {
+                    if app_state.profile_selected + 1 < app_state.profiles.len() {
+                        app_state.profile_selected += 1;
+                    }
+                    (InputAction::Continue, menu_state_changed)
+                }
+                Action::Top => {
+                    app_state.profile_selected = 0;
+                    (InputAction::Continue, menu_state_changed)
+                }
+                Action::Select if !app_state.profiles.is_empty() => (InputAction::RunProfile, menu_state_changed),
+                Action::Back => {
+                    *menu_state = MenuState::Categories;
+                    (InputAction::Continue, menu_state_changed)
+                }
+                _ => (InputAction::Continue, menu_state_changed),
+            },
+            MenuState::Queue => match action {
+                Action::Select if app_state.queue.as_ref().is_some_and(|queue| queue.paused) => {
+                    if let Some(queue) = app_state.queue.as_mut() {
+                        queue.resume();
+                    }
+                    (InputAction::ContinueQueue, menu_state_changed)
+                }
+                Action::Back if app_state.queue.as_ref().is_some_and(|queue| !queue.is_done()) => {
+                    if let Some(queue) = app_state.queue.as_mut() {
+                        queue.stop();
+                        let (succeeded, failed, skipped) = queue.counts();
+                        logging::info("Queue stopped", json!({ "succeeded": succeeded, "failed": failed, "skipped": skipped }));
+                    }
+                    (InputAction::Continue, menu_state_changed)
+                }
+                Action::Select | Action::Back => {
+                    app_state.queue = None;
+                    *menu_state = MenuState::Categories;
+                    menu_state_changed = true;
+                    (InputAction::Continue, menu_state_changed)
+                }
+                _ => (InputAction::Continue, menu_state_changed),
+            },
+            // Handled before the global shortcuts
+            MenuState::Output => (InputAction::Continue, menu_state_changed),
+        },
+    }
+}
+
+/// The keymap section that applies on a screen.
+fn key_screen(menu_state: MenuState) -> keymap::Screen {
+    match menu_state {
+        MenuState::Categories => keymap::Screen::Categories,
+        MenuState::Programs => keymap::Screen::Programs,
+        MenuState::Search => keymap::Screen::Search,
+        MenuState::Help => keymap::Screen::Help,
+        MenuState::SystemInfo => keymap::Screen::SystemInfo,
+        MenuState::History => keymap::Screen::History,
+        MenuState::Doctor => keymap::Screen::Doctor,
+        MenuState::Preview => keymap::Screen::Preview,
+        MenuState::Themes => keymap::Screen::Themes,
+        MenuState::Queue => keymap::Screen::Queue,
+        MenuState::Profiles => keymap::Screen::Profiles,
+        // The output viewer handles its own keys
+        MenuState::Output => keymap::Screen::Global,
+    }
+}
+
+fn update_filtered_programs(
+    categories: &[Category],
+    search_query: &str,
+    contents: bool,
+    filtered_programs: &mut Vec<search::SearchResult>,
+    selected_program: &mut usize,
+    program_state: &mut ListState,
+) {
+    *filtered_programs = if contents {
+        search::search_contents(categories, search_query)
+    } else {
+        search::search(categories, search_query)
+    };
+    // The best match moves to the top, so start from there
+    *selected_program = 0;
+    program_state.select(Some(0));
+}
+
+#[allow(clippy::too_many_arguments)]
+fn draw_ui<B: Backend>(
+    f: &mut Frame<B>,
+    categories: &[Category],
+    selected_category: usize,
+    category_state: &mut ListState,
+    program_state: &mut ListState,
+    menu_state: &MenuState,
+    search_query: &str,
+    filtered_programs: &[search::SearchResult],
+    update_available: &Option<update::Release>,
+    theme: &theme::Theme,
+    app_state: &AppState,
+) {
+
+    let size = f.size();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),  // Title
+            Constraint::Length(3),  // Search bar
+            Constraint::Min(10),    // Main content (categories and programs)
+            Constraint::Length(3),  // OS info
+            Constraint::Length(3),  // Help text
+            Constraint::Length(3),  // Quote (new)
+        ].as_ref())
+        .split(size);
 
-    if let Some(new_version) = update_available {
-        title_text.push(Line::from(vec![
+    // Modified title section
+    let current_date = Local::now().format("%Y-%m-%d").to_string();
+    let mut title_spans = vec![
+            Span::styled("Linux Toolbox ", Style::default().fg(theme.title).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("v{}", CURRENT_VERSION), Style::default().fg(theme.accent)),
+            Span::raw(" by "),
+            Span::styled("Tech Logicals", Style::default().fg(theme.accent).add_modifier(Modifier::ITALIC)),
             Span::raw(" | "),
-            Span::styled(format!("Update v{} available", new_version), Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
-        ]));
+            Span::styled(current_date, Style::default().fg(theme.muted)),
+            Span::raw(" | Theme: "),
+            Span::styled(theme.name.as_str(), Style::default().fg(theme.accent)),
+    ];
+
+    if app_state.running_as_root {
+        title_spans.push(Span::raw(" | "));
+        title_spans.push(Span::styled("ROOT", Style::default().fg(theme.error).add_modifier(Modifier::BOLD)));
+    }
+    if let Some(release) = update_available {
+        title_spans.push(Span::raw(" | "));
+        let install = app_state.keymap.hints(keymap::Screen::Global, &[(&[Action::InstallUpdate], "install")]);
+        let text = if install.is_empty() {
+            format!("Update {} available", release.tag_name)
+        } else {
+            format!("Update {} available ({})", release.tag_name, install)
+        };
+        title_spans.push(Span::styled(text, Style::default().fg(theme.warning).add_modifier(Modifier::BOLD)));
     }
 
-    let title = Paragraph::new(title_text)
+    let title = Paragraph::new(Line::from(title_spans))
         .alignment(Alignment::Center)
-        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(fg_color).bg(bg_color)));
+        .block(Block::default().borders(Borders::ALL).border_style(theme.border_style()));
     f.render_widget(title, chunks[0]);
 
     // Search bar
     let search_bar = Paragraph::new(search_query)
-        .style(Style::default().fg(Color::Cyan))
-        .block(Block::default().borders(Borders::ALL).title("Search").border_style(Style::default().fg(fg_color).bg(bg_color)));
+        .style(Style::default().fg(theme.accent))
+        .block(Block::default().borders(Borders::ALL).title(search_title(menu_state, app_state)).border_style(theme.border_style()));
     f.render_widget(search_bar, chunks[1]);
 
     // Main content
@@ -623,96 +1280,425 @@ fn draw_ui<B: Backend>(
     let category_items: Vec<ListItem> = categories
         .iter()
         .map(|c| {
+            let bullet = match c.kind {
+                CategoryKind::Favorites => "★ ",
+                CategoryKind::Recent => "↺ ",
+                CategoryKind::Catalog => "• ",
+            };
             ListItem::new(Line::from(vec![
-                Span::styled("• ", Style::default().fg(Color::Cyan)),
+                Span::styled(bullet, Style::default().fg(theme.accent)),
                 Span::raw(c.name.clone()),
             ]))
         })
         .collect();
 
     let categories_list = List::new(category_items)
-        .block(Block::default().title("Categories").borders(Borders::ALL).border_style(Style::default().fg(fg_color).bg(bg_color)))
-        .highlight_style(Style::default().bg(Color::Cyan).fg(bg_color).add_modifier(Modifier::BOLD))
+        .block(Block::default().title("Categories").borders(Borders::ALL).border_style(theme.border_style()))
+        .highlight_style(theme.selected())
         .highlight_symbol(">> ");
 
     f.render_stateful_widget(categories_list, main_chunks[0], category_state);
 
     // Programs list
     let program_items: Vec<ListItem> = if *menu_state == MenuState::Search {
-        filtered_programs.iter().map(|(_, p, _)| {
-            ListItem::new(Line::from(vec![
-                Span::styled("▶ ", Style::default().fg(Color::Cyan)),
-                Span::raw(p.clone()),
-            ]))
+        let hit = Style::default().fg(theme.accent).add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+        let muted = Style::default().fg(theme.muted);
+        filtered_programs.iter().map(|result| {
+            let marked = app_state.marked.iter().any(|key| *key == format!("{}/{}", result.category, result.name));
+            let mut spans = vec![if marked {
+                Span::styled("✔ ", Style::default().fg(theme.success))
+            } else {
+                Span::styled("▶ ", Style::default().fg(theme.accent))
+            }];
+            spans.extend(highlight_matches(&result.name, &result.name_matches, Style::default(), hit));
+            spans.push(Span::styled("  ", muted));
+            spans.extend(highlight_matches(&result.category, &result.category_matches, muted, hit));
+            if let Some(program) = find_program_by_key(categories, &format!("{}/{}", result.category, result.name)) {
+                spans.extend(program_badges(program, theme));
+            }
+            if let Some(context) = &result.context {
+                spans.push(Span::styled(format!("  · {}: ", context.label), muted));
+                spans.extend(highlight_matches(&context.text, &context.positions, muted, hit));
+            }
+            let mut lines = vec![Line::from(spans)];
+            for line in &result.lines {
+                let location = match &line.file {
+                    Some(file) => format!("    {}:{}  ", file, line.number),
+                    None => format!("    {}  ", line.number),
+                };
+                let mut spans = vec![Span::styled(location, muted)];
+                spans.extend(highlight_matches(&line.text, &line.positions, Style::default(), hit));
+                lines.push(Line::from(spans));
+            }
+            ListItem::new(lines)
         }).collect()
     } else {
         categories[selected_category].programs.iter().map(|p| {
-            ListItem::new(Line::from(vec![
-                Span::styled(if p.is_favorite { "★ " } else { "▶ " }, Style::default().fg(Color::Cyan)),
-                Span::raw(p.name.clone()),
-            ]))
+            let bullet = if app_state.marked.contains(&p.key()) {
+                Span::styled("✔ ", Style::default().fg(theme.success))
+            } else {
+                Span::styled(if p.is_favorite { "★ " } else { "▶ " }, Style::default().fg(theme.accent))
+            };
+            let mut spans = vec![bullet, Span::raw(p.name.clone())];
+            spans.extend(program_badges(p, theme));
+            ListItem::new(Line::from(spans))
         }).collect()
     };
 
+    let programs_title = match app_state.marked.len() {
+        0 => "Programs".to_string(),
+        count => format!("Programs ({} marked, {}: run)", count, app_state.keymap.keys(key_screen(*menu_state), Action::RunQueue)),
+    };
     let programs_list = List::new(program_items)
-        .block(Block::default().title("Programs").borders(Borders::ALL).border_style(Style::default().fg(fg_color).bg(bg_color)))
-        .highlight_style(Style::default().bg(Color::Cyan).fg(bg_color).add_modifier(Modifier::BOLD))
+        .block(Block::default().title(programs_title).borders(Borders::ALL).border_style(theme.border_style()))
+        .highlight_style(theme.selected())
         .highlight_symbol(">> ");
 
-    f.render_stateful_widget(programs_list, main_chunks[1], program_state);
+    let program_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(3),
+            Constraint::Length(8),
+        ].as_ref())
+        .split(main_chunks[1]);
+
+    f.render_stateful_widget(programs_list, program_chunks[0], program_state);
+
+    let selected = program_state.selected().unwrap_or(0);
+    let selected_program = if *menu_state == MenuState::Search {
+        filtered_programs.get(selected).and_then(|result| {
+            categories
+                .iter()
+                .find(|c| c.name == result.category)
+                .and_then(|c| c.programs.iter().find(|p| p.name == result.name))
+        })
+    } else {
+        categories[selected_category].programs.get(selected)
+    };
+    draw_program_details(f, program_chunks[1], selected_program, theme);
 
     // System info (now just showing OS)
     let os_info = app_state.system_info.lines().next().unwrap_or("Unknown OS");
     let system_info = Paragraph::new(os_info)
-        .style(Style::default().fg(fg_color))
-        .block(Block::default().title("OS").borders(Borders::ALL).border_style(Style::default().fg(fg_color).bg(bg_color)));
+        .style(Style::default().fg(theme.foreground))
+        .block(Block::default().title("OS").borders(Borders::ALL).border_style(theme.border_style()));
     f.render_widget(system_info, chunks[3]);
 
     // Help text
+    let keys = &app_state.keymap;
+    let screen = key_screen(*menu_state);
     let help_text = match menu_state {
-        MenuState::Categories => "Mouse/↑↓: Move | Enter/Click: Select | /: Search | Tab: Theme | h: Help | i: Info | q: Quit | 1-9: Quick Select",
-        MenuState::Programs => "Mouse/↑↓: Move | Enter/Click: Run | Esc: Back | f: Favorite | /: Search | h: Help | i: Info | q: Quit",
-        MenuState::Search => "Type to search | Enter/Click: Select | Esc: Cancel | Tab: Theme | h: Help | i: Info",
-        MenuState::Help => "Press 'h' or Esc to return",
-        MenuState::SystemInfo => "Press 'i' or Esc to return",
+        MenuState::Categories => format!(
+            "{} | 1-9: Quick Select",
+            keys.hints(screen, &[
+                (&[Action::Up, Action::Down], "Move"),
+                (&[Action::Select], "Select"),
+                (&[Action::Search], "Search"),
+                (&[Action::NextTheme, Action::Themes], "Theme"),
+                (&[Action::Help], "Help"),
+                (&[Action::SystemInfo], "Info"),
+                (&[Action::Quit], "Quit"),
+            ]),
+        ),
+        MenuState::Programs => keys.hints(screen, &[
+            (&[Action::Up, Action::Down], "Move"),
+            (&[Action::Select], "Run"),
+            (&[Action::Preview], "Preview"),
+            (&[Action::Back], "Back"),
+            (&[Action::Favorite], "Favorite"),
+            (&[Action::Mark], "Mark"),
+            (&[Action::Search], "Search"),
+            (&[Action::Help], "Help"),
+            (&[Action::Quit], "Quit"),
+        ]),
+        MenuState::Search => format!(
+            "Type to search | {}",
+            keys.hints(screen, &[(&[Action::Up, Action::Down], "Move"), (&[Action::Select], "Select"), (&[Action::Mark], "Mark"), (&[Action::Back], "Cancel")]),
+        ),
+        // The other screens are drawn full-screen with their own help line
+        _ => String::new(),
     };
 
     let help_paragraph = Paragraph::new(help_text)
-        .style(Style::default().fg(fg_color))
+        .style(Style::default().fg(theme.foreground))
         .alignment(Alignment::Center)
-        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(fg_color).bg(bg_color)));
+        .block(Block::default().borders(Borders::ALL).border_style(theme.border_style()));
     f.render_widget(help_paragraph, chunks[4]);
 
     // Quote
     let quote = Paragraph::new(app_state.current_quote.as_str())
-        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::ITALIC))
+        .style(Style::default().fg(theme.accent).add_modifier(Modifier::ITALIC))
         .alignment(Alignment::Center)
-        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(fg_color).bg(bg_color)));
+        .block(Block::default().borders(Borders::ALL).border_style(theme.border_style()));
     f.render_widget(quote, chunks[5]);
 
     // Status message
     if let Some(message) = &app_state.status_message {
         let status_bar = Paragraph::new(message.as_str())
-            .style(Style::default().fg(Color::Cyan))
+            .style(Style::default().fg(theme.status_bar))
             .alignment(Alignment::Center);
         f.render_widget(status_bar, chunks[4]);
     }
 
     // Loading animation
-    if app_state.loading {
-        draw_loading_animation(f, color_scheme, app_state.loading_progress);
+}
+
+/// Index of the search result drawn on a row of the list, counting the matching lines under each.
+fn search_result_at(results: &[search::SearchResult], row: usize) -> Option<usize> {
+    let mut bottom = 0;
+    results.iter().position(|result| {
+        bottom += 1 + result.lines.len();
+        row < bottom
+    })
+}
+
+fn search_title(menu_state: &MenuState, app_state: &AppState) -> String {
+    let toggle = app_state.keymap.keys(keymap::Screen::Search, Action::SearchContents);
+    match (*menu_state == MenuState::Search, app_state.search_contents, toggle.is_empty()) {
+        (false, _, _) => "Search".to_string(),
+        (true, true, false) => format!("Search script contents ({}: names)", toggle),
+        (true, false, false) => format!("Search ({}: script contents)", toggle),
+        (true, true, true) => "Search script contents".to_string(),
+        (true, false, true) => "Search".to_string(),
     }
 }
 
-fn draw_loading_animation<B: Backend>(f: &mut Frame<B>, color_scheme: &ColorScheme, progress: u8) {
-    let (_, fg_color, _) = color_scheme.get_colors();
-    let loading_text = format!("Loading {}", ".".repeat(progress as usize));
-    let loading_widget = Paragraph::new(loading_text)
-        .style(Style::default().fg(fg_color))
-        .alignment(Alignment::Center);
-    let area = centered_rect(30, 3, f.size());
+/// Splits text into spans, styling the characters at `positions` with `hit`.
+fn highlight_matches(text: &str, positions: &[usize], base: Style, hit: Style) -> Vec<Span<'static>> {
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut run = String::new();
+    let mut run_is_hit = false;
+    for (i, c) in text.chars().enumerate() {
+        let is_hit = positions.binary_search(&i).is_ok();
+        if is_hit != run_is_hit && !run.is_empty() {
+            spans.push(Span::styled(std::mem::take(&mut run), if run_is_hit { hit } else { base }));
+        }
+        run_is_hit = is_hit;
+        run.push(c);
+    }
+    if !run.is_empty() {
+        spans.push(Span::styled(run, if run_is_hit { hit } else { base }));
+    }
+    spans
+}
+
+/// "destructive" and "untested" markers shown after a program's name.
+fn program_badges(program: &Program, theme: &theme::Theme) -> Vec<Span<'static>> {
+    let mut badges = Vec::new();
+    if program.risk == RiskLevel::Destructive {
+        badges.push(Span::styled("  ‼ destructive", Style::default().fg(theme.error).add_modifier(Modifier::BOLD)));
+    }
+    if program.untested {
+        badges.push(Span::styled("  ⚠ untested", Style::default().fg(theme.warning)));
+    }
+    badges
+}
+
+fn draw_program_details<B: Backend>(f: &mut Frame<B>, area: Rect, program: Option<&Program>, theme: &theme::Theme) {
+    let label = Style::default().fg(theme.accent).add_modifier(Modifier::BOLD);
+    let join_or = |values: &[String], empty: &'static str| {
+        if values.is_empty() { empty.to_string() } else { values.join(", ") }
+    };
+
+    let text = match program {
+        Some(p) => vec![
+            Line::from(p.description.clone().unwrap_or_else(|| "No description".to_string())),
+            Line::from(vec![
+                Span::styled("Tags: ", label),
+                Span::raw(join_or(&p.tags, "none")),
+                Span::styled("  Aliases: ", label),
+                Span::raw(join_or(&p.aliases, "none")),
+            ]),
+            Line::from(vec![
+                Span::styled("Distros: ", label),
+                Span::raw(join_or(&p.distros, "any")),
+                Span::styled("  Parameters: ", label),
+                Span::raw(join_or(&p.params.iter().map(|param| param.name.clone()).collect::<Vec<_>>(), "none")),
+            ]),
+            Line::from(vec![
+                Span::styled("Root: ", label),
+                Span::raw(if p.requires_root { "required" } else { "no" }),
+                Span::styled("  Risk: ", label),
+                Span::styled(p.risk.display_name().to_string(), match p.risk {
+                    RiskLevel::Safe => Style::default(),
+                    RiskLevel::SystemChanging => Style::default().fg(theme.warning),
+                    RiskLevel::Destructive => Style::default().fg(theme.error).add_modifier(Modifier::BOLD),
+                }),
+                Span::styled("  Tested: ", label),
+                Span::styled(if p.untested { "no" } else { "yes" }, if p.untested { Style::default().fg(theme.warning) } else { Style::default() }),
+                Span::styled("  Pinned: ", label),
+                Span::raw(if p.sha256.is_some() || p.locked_sha256.is_some() { "sha256" } else { "no" }),
+            ]),
+            Line::from(vec![
+                Span::styled("Script: ", label),
+                Span::raw(p.script.display().to_string()),
+            ]),
+            Line::from(vec![
+                Span::styled("Source: ", label),
+                Span::raw(format!("{} ({})", p.source.source.display_name(), p.source.path.display())),
+            ]),
+        ],
+        None => vec![Line::from("No program selected")],
+    };
+
+    let details = Paragraph::new(text)
+        .style(Style::default().fg(theme.foreground))
+        .wrap(Wrap { trim: true })
+        .block(Block::default().title("Details").borders(Borders::ALL).border_style(theme.border_style()));
+    f.render_widget(details, area);
+}
+
+fn draw_modified_warning<B: Backend>(f: &mut Frame<B>, theme: &theme::Theme, keys: &keymap::Keymap, pending: &PendingRun) {
+    let area = centered_rect(70, 40, f.size());
+    let label = Style::default().fg(theme.accent).add_modifier(Modifier::BOLD);
+
+    let text = vec![
+        Line::from(Span::styled(
+            format!("{} has changed since it was pinned.", pending.program.key()),
+            Style::default().fg(theme.error).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(vec![Span::styled("File:     ", label), Span::raw(pending.file.display().to_string())]),
+        Line::from(vec![Span::styled("Expected: ", label), Span::raw(pending.expected.clone())]),
+        Line::from(vec![Span::styled("Actual:   ", label), Span::raw(pending.actual.clone())]),
+        Line::from(""),
+        Line::from("Review the changes and re-pin with `linuxtoolbox pin` if they are expected."),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("Press {} to run it anyway, any other key to cancel", keys.keys(keymap::Screen::Global, Action::Yes)),
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+    ];
+
+    let warning = Paragraph::new(text)
+        .style(theme.text())
+        .wrap(Wrap { trim: false })
+        .block(Block::default().title("Checksum mismatch").borders(Borders::ALL).border_style(Style::default().fg(theme.error).bg(theme.background)));
     f.render_widget(Clear, area);
-    f.render_widget(loading_widget, area);
+    f.render_widget(warning, area);
+}
+
+fn draw_risk_confirm<B: Backend>(f: &mut Frame<B>, theme: &theme::Theme, keys: &keymap::Keymap, confirm: &RiskConfirm) {
+    let area = centered_rect(60, 40, f.size());
+    let program = &confirm.program;
+    let danger = Style::default().fg(theme.error).add_modifier(Modifier::BOLD);
+
+    let mut text = vec![
+        Line::from(Span::styled(format!("{} is destructive.", program.key()), danger)),
+        Line::from(""),
+        Line::from(program.description.clone().unwrap_or_else(|| "It can erase data or leave the system unusable.".to_string())),
+    ];
+    if confirm.queued {
+        text.push(Line::from("It is part of the queue you are starting."));
+    }
+    text.extend([
+        Line::from(""),
+        Line::from(vec![Span::raw("Type "), Span::styled(program.name.clone(), danger), Span::raw(" to run it:")]),
+        Line::from(Span::styled(format!("{}_", confirm.typed), Style::default().fg(theme.accent))),
+        Line::from(""),
+    ]);
+    if let Some(error) = &confirm.error {
+        text.push(Line::from(Span::styled(error.clone(), danger)));
+        text.push(Line::from(""));
+    }
+    let screen = keymap::Screen::Confirm;
+    text.push(Line::from(keys.hints(screen, &[(&[Action::Select], "Run"), (&[Action::Back], "Cancel")])));
+
+    let confirm_widget = Paragraph::new(text)
+        .style(theme.text())
+        .wrap(Wrap { trim: false })
+        .block(Block::default().title("Destructive program").borders(Borders::ALL).border_style(Style::default().fg(theme.error).bg(theme.background)));
+    f.render_widget(Clear, area);
+    f.render_widget(confirm_widget, area);
+}
+
+fn draw_sudo_prompt<B: Backend>(f: &mut Frame<B>, theme: &theme::Theme, keys: &keymap::Keymap, prompt: &privilege::SudoPrompt, queue: Option<&queue::RunQueue>) {
+    let area = centered_rect(60, 40, f.size());
+    let needs = match (&prompt.pending, queue) {
+        (Some((program, _)), _) => format!("{} needs root.", program.key()),
+        (None, Some(queue)) => {
+            let root: Vec<String> = queue.items.iter().filter(|program| program.requires_root).map(Program::key).collect();
+            format!("These queued programs need root: {}", root.join(", "))
+        }
+        (None, None) => "The next programs need root.".to_string(),
+    };
+
+    let mut text = vec![
+        Line::from(Span::styled(needs, Style::default().add_modifier(Modifier::BOLD))),
+        Line::from(""),
+        Line::from(if prompt.pending.is_some() {
+            "The password is typed into the script's terminal as it starts, then forgotten. Its sudo session is kept alive while it runs."
+        } else {
+            "Enter it once for the whole queue. It is kept only until the last of these programs starts, and each one's sudo session is kept alive while it runs."
+        }),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Password: ", Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+            Span::raw(format!("{}_", "•".repeat(prompt.password.char_count()))),
+        ]),
+        Line::from(""),
+    ];
+    if let Some(error) = &prompt.error {
+        text.push(Line::from(Span::styled(error.clone(), Style::default().fg(theme.error).add_modifier(Modifier::BOLD))));
+        text.push(Line::from(""));
+    }
+    let screen = keymap::Screen::Sudo;
+    text.push(Line::from(keys.hints(screen, &[(&[Action::Select], "Authenticate"), (&[Action::Back], "Cancel")])));
+
+    let prompt_widget = Paragraph::new(text)
+        .style(theme.text())
+        .wrap(Wrap { trim: false })
+        .block(Block::default().title("sudo").borders(Borders::ALL).border_style(Style::default().fg(theme.warning).bg(theme.background)));
+    f.render_widget(Clear, area);
+    f.render_widget(prompt_widget, area);
+}
+
+fn draw_param_form<B: Backend>(f: &mut Frame<B>, theme: &theme::Theme, keys: &keymap::Keymap, form: &params::ParamForm) {
+    let params = &form.program.params;
+    let height = (params.len() as u16 + 8).min(f.size().height);
+    let width = f.size().width * 7 / 10;
+    let area = Rect::new(f.size().x + (f.size().width - width) / 2, f.size().y + (f.size().height - height) / 2, width, height);
+    let label_width = params.iter().map(|param| param.name.chars().count()).max().unwrap_or(0);
+
+    let mut text = Vec::new();
+    for (index, (param, value)) in params.iter().zip(&form.values).enumerate() {
+        let selected = index == form.selected;
+        let shown = match param.kind {
+            _ if param.secret => "•".repeat(value.chars().count()),
+            params::ParamType::Boolean | params::ParamType::Choice => format!("◀ {} ▶", value),
+            _ => value.clone(),
+        };
+        let field_style = if selected { theme.selected() } else { Style::default().fg(theme.accent) };
+        let cursor = if selected && matches!(param.kind, params::ParamType::String | params::ParamType::Integer) { "_" } else { "" };
+        text.push(Line::from(vec![
+            Span::styled(format!("{} {:<width$}  ", if selected { ">" } else { " " }, param.name, width = label_width), Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{}{}", shown, cursor), field_style),
+            Span::styled(format!("  {}{}", param.type_name(), if param.secret { ", secret" } else { "" }), Style::default().fg(theme.muted)),
+        ]));
+    }
+    text.push(Line::from(""));
+    let selected = &params[form.selected];
+    text.push(match &form.error {
+        Some(error) => Line::from(Span::styled(error.clone(), Style::default().fg(theme.error).add_modifier(Modifier::BOLD))),
+        None => Line::from(Span::styled(selected.description.clone().unwrap_or_default(), Style::default().fg(theme.muted))),
+    });
+    let passed = match &selected.env {
+        Some(variable) => format!("Passed as ${}", variable),
+        None => format!("Passed as argument {}", params.iter().filter(|param| param.env.is_none()).position(|param| param.name == selected.name).map_or(0, |i| i + 1)),
+    };
+    text.push(Line::from(Span::styled(passed, Style::default().fg(theme.muted))));
+    text.push(Line::from(""));
+    let screen = keymap::Screen::Form;
+    text.push(Line::from(format!(
+        "{} | ←→/Space: Change choice",
+        keys.hints(screen, &[(&[Action::Up, Action::Down], "Field"), (&[Action::Select], "Run"), (&[Action::Back], "Cancel")]),
+    )));
+
+    let form_widget = Paragraph::new(text)
+        .style(theme.text())
+        .block(Block::default().title(format!("Parameters for {}", form.program.key())).borders(Borders::ALL).border_style(theme.border_style()));
+    f.render_widget(Clear, area);
+    f.render_widget(form_widget, area);
 }
 
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
@@ -725,122 +1711,978 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         ].as_ref())
         .split(r);
 
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage((100 - percent_x) / 2),
-            Constraint::Percentage(percent_x),
-            Constraint::Percentage((100 - percent_x) / 2),
-        ].as_ref())
-        .split(popup_layout[1])[1]
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ].as_ref())
+        .split(popup_layout[1])[1]
+}
+
+fn get_system_info() -> String {
+    let mut sys = System::new_all();
+    sys.refresh_all();
+
+    let cpu_info = sys.cpus().first().map(|cpu| cpu.brand()).unwrap_or("Unknown CPU");
+    let total_memory = sys.total_memory() / 1024 / 1024; // Convert to MB
+    let used_memory = sys.used_memory() / 1024 / 1024; // Convert to MB
+    let gpu_info = "GPU info not available"; // sysinfo doesn't provide GPU info easily
+
+    let os_info = if let Some(os_version) = sys.long_os_version() {
+        os_version
+    } else {
+        sys.name().unwrap_or_else(|| "Unknown OS".to_string())
+    };
+
+    format!(
+        "OS: {}\nCPU: {}\nMemory: {} MB / {} MB\nGPU: {}",
+        os_info, cpu_info, used_memory, total_memory, gpu_info
+    )
+}
+
+fn draw_system_info_screen<B: Backend>(f: &mut Frame<B>, theme: &theme::Theme, keys: &keymap::Keymap, system_info: &str) {
+    
+    let system_info_lines: Vec<Line> = system_info
+        .lines()
+        .map(|line| {
+            let parts: Vec<&str> = line.splitn(2, ": ").collect();
+            if parts.len() == 2 {
+                Line::from(vec![
+                    Span::styled(format!("{}: ", parts[0]), Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+                    Span::raw(parts[1]),
+                ])
+            } else {
+                Line::from(line)
+            }
+        })
+        .collect();
+
+    let mut text = vec![
+        Line::from(vec![
+            Span::styled("System Information", Style::default().fg(theme.accent).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(""),
+    ];
+    text.extend(system_info_lines);
+    text.push(Line::from(""));
+    let close = keys.hints(keymap::Screen::SystemInfo, &[(&[Action::SystemInfo, Action::Back], "Return to the main menu")]);
+    text.push(Line::from(Span::styled(close, Style::default().fg(theme.accent).add_modifier(Modifier::BOLD))));
+
+    let system_info_paragraph = Paragraph::new(text)
+        .block(Block::default().title("System Info").borders(Borders::ALL))
+        .style(theme.text())
+        .alignment(Alignment::Left)
+        .wrap(Wrap { trim: true });
+
+    let area = centered_rect(60, 40, f.size());
+    f.render_widget(Clear, area);
+    f.render_widget(system_info_paragraph, area);
+}
+
+fn draw_history_screen<B: Backend>(f: &mut Frame<B>, theme: &theme::Theme, keys: &keymap::Keymap, history: &[state::RunRecord], selected: usize) {
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),
+            Constraint::Length(3),
+        ].as_ref())
+        .split(f.size());
+
+    let items: Vec<ListItem> = history
+        .iter()
+        .map(|record| {
+            let (marker, marker_color) = if record.success { ("✔ ", theme.success) } else { ("✘ ", theme.error) };
+            ListItem::new(Line::from(vec![
+                Span::styled(marker, Style::default().fg(marker_color)),
+                Span::raw(format!("{}  ", record.started_at.format("%Y-%m-%d %H:%M:%S"))),
+                Span::styled(record.key.clone(), Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!("  {} in {}", record.status_text(), state::format_duration(record.duration()))),
+            ]))
+        })
+        .collect();
+
+    let title = if history.is_empty() { "Run History (no runs yet)" } else { "Run History" };
+    let list = List::new(items)
+        .block(Block::default().title(title).borders(Borders::ALL))
+        .style(theme.text())
+        .highlight_style(theme.selected())
+        .highlight_symbol(">> ");
+
+    let mut list_state = ListState::default();
+    list_state.select((!history.is_empty()).then_some(selected));
+    f.render_stateful_widget(list, chunks[0], &mut list_state);
+
+    let screen = keymap::Screen::History;
+    let help_text = match history.get(selected) {
+        Some(record) if record.output.is_some() => keys.hints(screen, &[
+            (&[Action::Up, Action::Down], "Move"),
+            (&[Action::Select], "Re-run"),
+            (&[Action::Output], "View output"),
+            (&[Action::History, Action::Back], "Back"),
+        ]),
+        _ => keys.hints(screen, &[(&[Action::Up, Action::Down], "Move"), (&[Action::Select], "Re-run"), (&[Action::History, Action::Back], "Back")]),
+    };
+    let help = Paragraph::new(help_text)
+        .style(theme.text())
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL));
+    f.render_widget(help, chunks[1]);
+}
+
+fn draw_doctor_screen<B: Backend>(f: &mut Frame<B>, theme: &theme::Theme, app_state: &AppState) {
+    let findings = &app_state.doctor_findings;
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),
+            Constraint::Length(3),
+        ].as_ref())
+        .split(f.size());
+
+    let items: Vec<ListItem> = findings
+        .iter()
+        .map(|finding| {
+            let severity_color = match finding.severity {
+                doctor::Severity::Error => theme.error,
+                doctor::Severity::Warning => theme.warning,
+                doctor::Severity::Info => theme.accent,
+            };
+            let mut lines = vec![Line::from(vec![
+                Span::styled(format!("{:<8}", finding.severity.display_name()), Style::default().fg(severity_color).add_modifier(Modifier::BOLD)),
+                Span::styled(
+                    finding.program.clone().unwrap_or_else(|| finding.path.display().to_string()),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!("  {}", finding.message)),
+            ])];
+            if finding.program.is_some() {
+                lines.push(Line::from(format!("{:<8}{}", "", finding.path.display())));
+            }
+            if let Some(fix) = &finding.fix {
+                lines.push(Line::from(Span::styled(format!("{:<8}Fix: {}", "", fix.description()), Style::default().fg(theme.success))));
+            }
+            ListItem::new(lines)
+        })
+        .collect();
+
+    let errors = findings.iter().filter(|f| f.severity == doctor::Severity::Error).count();
+    let warnings = findings.iter().filter(|f| f.severity == doctor::Severity::Warning).count();
+    let title = if findings.is_empty() {
+        "Doctor (no problems found)".to_string()
+    } else {
+        format!("Doctor ({} errors, {} warnings, {} notes)", errors, warnings, findings.len() - errors - warnings)
+    };
+    let list = List::new(items)
+        .block(Block::default().title(title).borders(Borders::ALL))
+        .style(theme.text())
+        .highlight_style(theme.selected())
+        .highlight_symbol(">> ");
+
+    let mut list_state = ListState::default();
+    list_state.select((!findings.is_empty()).then_some(app_state.doctor_selected));
+    f.render_stateful_widget(list, chunks[0], &mut list_state);
+
+    let help_text = app_state
+        .status_message
+        .clone()
+        .unwrap_or_else(|| app_state.keymap.hints(keymap::Screen::Doctor, &[
+            (&[Action::Up, Action::Down], "Move"),
+            (&[Action::Fix], "Apply fix"),
+            (&[Action::FixAll], "Fix all"),
+            (&[Action::Doctor, Action::Back], "Back"),
+        ]));
+    let help = Paragraph::new(help_text)
+        .style(theme.text())
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL));
+    f.render_widget(help, chunks[1]);
+}
+
+fn draw_theme_picker<B: Backend>(f: &mut Frame<B>, themes: &theme::ThemeSet, keys: &keymap::Keymap, selected: usize) {
+    let theme = themes.current();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(10),
+            Constraint::Length(3),
+        ].as_ref())
+        .split(f.size());
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(40),
+            Constraint::Percentage(60),
+        ].as_ref())
+        .split(chunks[0]);
+
+    let name_width = themes.themes().iter().map(|t| t.name.chars().count()).max().unwrap_or(0);
+    let items: Vec<ListItem> = themes
+        .themes()
+        .iter()
+        .enumerate()
+        .map(|(index, t)| {
+            let marker = if index == themes.current_index() { "✔ " } else { "  " };
+            let mut spans = vec![
+                Span::styled(marker, Style::default().fg(theme.success)),
+                Span::raw(format!("{:<width$} ", t.name, width = name_width)),
+            ];
+            spans.extend(
+                [t.background, t.foreground, t.accent, t.selection, t.warning, t.error, t.success]
+                    .into_iter()
+                    .map(|color| Span::styled("  ", Style::default().bg(color))),
+            );
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(Block::default().title(format!("Themes ({})", themes.themes().len())).borders(Borders::ALL).border_style(theme.border_style()))
+        .style(theme.text())
+        .highlight_style(theme.selected())
+        .highlight_symbol(">> ");
+    let mut list_state = ListState::default();
+    list_state.select(Some(selected));
+    f.render_stateful_widget(list, columns[0], &mut list_state);
+
+    if let Some(previewed) = themes.themes().get(selected) {
+        draw_theme_sample(f, columns[1], previewed);
+    }
+
+    let hints = keys.hints(keymap::Screen::Themes, &[(&[Action::Up, Action::Down], "Preview"), (&[Action::Select], "Apply"), (&[Action::Themes, Action::Back], "Back")]);
+    let help = Paragraph::new(format!("{} | Swatches: background, text, accent, selection, warning, error, success", hints))
+        .style(theme.text())
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL).border_style(theme.border_style()));
+    f.render_widget(help, chunks[1]);
+}
+
+/// A miniature main screen drawn entirely in `theme`, so it can be judged before applying it.
+fn draw_theme_sample<B: Backend>(f: &mut Frame<B>, area: Rect, theme: &theme::Theme) {
+    let outer = Block::default().title(format!("Preview — {}", theme.name)).borders(Borders::ALL).border_style(theme.border_style());
+    let inner = outer.inner(area);
+    f.render_widget(Paragraph::new("").style(theme.text()).block(outer), area);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(6),
+            Constraint::Length(5),
+            Constraint::Length(1),
+        ].as_ref())
+        .split(inner);
+
+    let title = Paragraph::new(Line::from(vec![
+        Span::styled("Linux Toolbox ", Style::default().fg(theme.title).add_modifier(Modifier::BOLD)),
+        Span::styled(format!("v{}", CURRENT_VERSION), Style::default().fg(theme.accent)),
+        Span::raw(" | "),
+        Span::styled("Update available", Style::default().fg(theme.warning).add_modifier(Modifier::BOLD)),
+    ]))
+    .style(theme.text())
+    .alignment(Alignment::Center)
+    .block(Block::default().borders(Borders::ALL).border_style(theme.border_style()));
+    f.render_widget(title, rows[0]);
+
+    let lists = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(45),
+            Constraint::Percentage(55),
+        ].as_ref())
+        .split(rows[1]);
+    let sample_list = |title: &'static str, entries: &[&'static str], bullet: &'static str| {
+        let items: Vec<ListItem> = entries
+            .iter()
+            .map(|entry| ListItem::new(Line::from(vec![Span::styled(bullet, Style::default().fg(theme.accent)), Span::raw(*entry)])))
+            .collect();
+        List::new(items)
+            .block(Block::default().title(title).borders(Borders::ALL).border_style(theme.border_style()))
+            .style(theme.text())
+            .highlight_style(theme.selected())
+            .highlight_symbol(">> ")
+    };
+    let mut sample_state = ListState::default();
+    sample_state.select(Some(1));
+    f.render_stateful_widget(sample_list("Categories", &["Favorites", "System", "Utilities"], "• "), lists[0], &mut sample_state);
+    f.render_stateful_widget(sample_list("Programs", &["Backup", "Update packages", "Clean cache"], "▶ "), lists[1], &mut sample_state);
+
+    let label = Style::default().fg(theme.accent).add_modifier(Modifier::BOLD);
+    let details = Paragraph::new(vec![
+        Line::from(vec![Span::styled("Script: ", label), Span::raw("scripts/update.sh")]),
+        Line::from(vec![
+            Span::styled("Last run: ", label),
+            Span::styled("✔ success", Style::default().fg(theme.success)),
+            Span::raw("  "),
+            Span::styled("✘ exit 1", Style::default().fg(theme.error)),
+        ]),
+        Line::from(Span::styled("# comments and line numbers", Style::default().fg(theme.muted).add_modifier(Modifier::ITALIC))),
+    ])
+    .style(theme.text())
+    .block(Block::default().title("Details").borders(Borders::ALL).border_style(theme.border_style()));
+    f.render_widget(details, rows[2]);
+
+    let status = Paragraph::new("Added Backup to favorites")
+        .style(Style::default().fg(theme.status_bar).bg(theme.background))
+        .alignment(Alignment::Center);
+    f.render_widget(status, rows[3]);
+}
+
+fn draw_profiles_screen<B: Backend>(
+    f: &mut Frame<B>,
+    theme: &theme::Theme,
+    keys: &keymap::Keymap,
+    profiles: &[Profile],
+    categories: &[Category],
+    selected: usize,
+) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),
+            Constraint::Length(3),
+        ].as_ref())
+        .split(f.size());
+    let panes = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(35),
+            Constraint::Percentage(65),
+        ].as_ref())
+        .split(chunks[0]);
+
+    let items: Vec<ListItem> = profiles
+        .iter()
+        .map(|profile| {
+            ListItem::new(Line::from(vec![
+                Span::styled("• ", Style::default().fg(theme.accent)),
+                Span::raw(profile.name.clone()),
+                Span::styled(format!("  {} programs", profile.programs.len()), Style::default().fg(theme.muted)),
+            ]))
+        })
+        .collect();
+    let title = if profiles.is_empty() { "Profiles (none in the catalog)" } else { "Profiles" };
+    let list = List::new(items)
+        .block(Block::default().title(title).borders(Borders::ALL).border_style(theme.border_style()))
+        .style(theme.text())
+        .highlight_style(theme.selected())
+        .highlight_symbol(">> ");
+    let mut list_state = ListState::default();
+    list_state.select((!profiles.is_empty()).then_some(selected));
+    f.render_stateful_widget(list, panes[0], &mut list_state);
+
+    let mut lines = Vec::new();
+    match profiles.get(selected) {
+        Some(profile) => {
+            if let Some(description) = &profile.description {
+                lines.push(Line::from(description.clone()));
+                lines.push(Line::from(""));
+            }
+            for (index, key) in profile.programs.iter().enumerate() {
+                let mut spans = vec![Span::styled(format!("{:>2}. ", index + 1), Style::default().fg(theme.muted))];
+                match find_program_by_key(categories, key) {
+                    Some(program) => {
+                        spans.push(Span::styled(key.clone(), Style::default().add_modifier(Modifier::BOLD)));
+                        if let Some(description) = &program.description {
+                            spans.push(Span::styled(format!("  {}", description), Style::default().fg(theme.muted)));
+                        }
+                    }
+                    None => {
+                        spans.push(Span::styled(key.clone(), Style::default().fg(theme.error).add_modifier(Modifier::BOLD)));
+                        spans.push(Span::styled("  not in the catalog", Style::default().fg(theme.error)));
+                    }
+                }
+                lines.push(Line::from(spans));
+            }
+        }
+        None => lines.push(Line::from(format!("Add a [{}] table to config.toml to define profiles", PROFILES_TABLE))),
+    }
+    let details = Paragraph::new(lines)
+        .block(Block::default().title("Programs, in run order").borders(Borders::ALL).border_style(theme.border_style()))
+        .style(theme.text())
+        .wrap(Wrap { trim: false });
+    f.render_widget(details, panes[1]);
+
+    let help = Paragraph::new(keys.hints(keymap::Screen::Profiles, &[
+        (&[Action::Up, Action::Down], "Move"),
+        (&[Action::Select], "Run all"),
+        (&[Action::Profiles, Action::Back], "Back"),
+    ]))
+    .style(theme.text())
+    .alignment(Alignment::Center)
+    .block(Block::default().borders(Borders::ALL).border_style(theme.border_style()));
+    f.render_widget(help, chunks[1]);
+}
+
+fn draw_queue_screen<B: Backend>(f: &mut Frame<B>, theme: &theme::Theme, keys: &keymap::Keymap, queue: &queue::RunQueue) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),
+            Constraint::Length(3),
+            Constraint::Length(3),
+        ].as_ref())
+        .split(f.size());
+
+    let items: Vec<ListItem> = queue
+        .items
+        .iter()
+        .enumerate()
+        .map(|(index, program)| {
+            let (marker, color, status) = match queue.outcomes.get(index) {
+                Some(queue::Outcome::Succeeded { duration }) => ("✔ ", theme.success, format!("succeeded in {}", state::format_duration(*duration))),
+                Some(queue::Outcome::Failed { exit_code: Some(code), .. }) => ("✘ ", theme.error, format!("failed with exit code {}", code)),
+                Some(queue::Outcome::Failed { error, .. }) => ("✘ ", theme.error, error.clone().unwrap_or_else(|| "did not start".to_string())),
+                Some(queue::Outcome::Skipped) => ("– ", theme.muted, "skipped".to_string()),
+                None if queue.current().is_some_and(|current| current.key() == program.key()) => ("▶ ", theme.warning, "running".to_string()),
+                None => ("· ", theme.muted, "waiting".to_string()),
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{:>2}. ", index + 1), Style::default().fg(theme.muted)),
+                Span::styled(marker, Style::default().fg(color)),
+                Span::styled(program.key(), Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(format!("  {}", status), Style::default().fg(color)),
+            ]))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(Block::default().title("Run Queue").borders(Borders::ALL).border_style(theme.border_style()))
+        .style(theme.text());
+    f.render_widget(list, chunks[0]);
+
+    let (succeeded, failed, skipped) = queue.counts();
+    let (summary, color) = if queue.is_done() {
+        let color = if failed > 0 { theme.error } else { theme.success };
+        (format!("Finished in {}: {} succeeded, {} failed, {} skipped", state::format_duration(Local::now() - queue.started_at), succeeded, failed, skipped), color)
+    } else if queue.paused {
+        let failed_program = queue.outcomes.len().checked_sub(1).and_then(|index| queue.items.get(index));
+        (format!("Paused after {} failed, {} left to run", failed_program.map_or(String::new(), |p| p.key()), queue.items.len() - queue.outcomes.len()), theme.warning)
+    } else {
+        (format!("Running {}/{}", queue.position(), queue.items.len()), theme.accent)
+    };
+    let status = Paragraph::new(summary)
+        .style(Style::default().fg(color).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL).border_style(theme.border_style()));
+    f.render_widget(status, chunks[1]);
+
+    let screen = keymap::Screen::Queue;
+    let help_text = if queue.is_done() {
+        keys.hints(screen, &[(&[Action::Select, Action::Back], "Back to menu")])
+    } else if queue.paused {
+        keys.hints(screen, &[(&[Action::Select], "Continue with the next program"), (&[Action::Back], "Stop the queue")])
+    } else {
+        keys.hints(screen, &[(&[Action::Back], "Stop the queue")])
+    };
+    let help = Paragraph::new(help_text)
+        .style(theme.text())
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL).border_style(theme.border_style()));
+    f.render_widget(help, chunks[2]);
+}
+
+fn draw_preview_screen<B: Backend>(f: &mut Frame<B>, theme: &theme::Theme, keys: &keymap::Keymap, preview: &preview::ScriptPreview) {
+    let label = Style::default().fg(theme.accent).add_modifier(Modifier::BOLD);
+    let sources_height = if preview.sources.is_empty() { 0 } else { preview.sources.len().min(6) as u16 + 2 };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(4),
+            Constraint::Min(5),
+            Constraint::Length(sources_height),
+            Constraint::Length(3),
+        ].as_ref())
+        .split(f.size());
+
+    let size = preview.size.map_or_else(|| "unknown".to_string(), |bytes| format!("{} bytes, {} lines", bytes, preview.lines.len()));
+    let modified = preview.modified.map_or_else(|| "unknown".to_string(), |time| time.format("%Y-%m-%d %H:%M:%S").to_string());
+    let header = Paragraph::new(vec![
+        Line::from(vec![Span::styled("Script: ", label), Span::raw(preview.program.script.display().to_string())]),
+        Line::from(vec![
+            Span::styled("Size: ", label),
+            Span::raw(size),
+            Span::styled("  Modified: ", label),
+            Span::raw(modified),
+        ]),
+    ])
+    .style(theme.text())
+    .block(Block::default().title(format!("Preview — {}", preview.program.key())).borders(Borders::ALL));
+    f.render_widget(header, chunks[0]);
+
+    let code_area = chunks[1];
+    let visible = code_area.height.saturating_sub(2) as usize;
+    preview.viewport.set(visible);
+    let gutter = preview.lines.len().to_string().len();
+    let code: Vec<Line> = match &preview.error {
+        Some(error) => vec![Line::from(Span::styled(format!("Cannot read script: {}", error), Style::default().fg(theme.error)))],
+        None => preview
+            .lines
+            .iter()
+            .enumerate()
+            .skip(preview.scroll)
+            .take(visible)
+            .map(|(index, line)| {
+                let mut spans = vec![Span::styled(format!("{:>width$} │ ", index + 1, width = gutter), Style::default().fg(theme.muted))];
+                spans.extend(preview::highlight_line(line, theme).spans);
+                Line::from(spans)
+            })
+            .collect(),
+    };
+    let position = format!("Lines {}-{} of {}", preview.scroll + 1, (preview.scroll + visible).min(preview.lines.len()), preview.lines.len());
+    let code = Paragraph::new(code)
+        .style(theme.text())
+        .block(Block::default().title(position).borders(Borders::ALL));
+    f.render_widget(code, code_area);
+
+    if !preview.sources.is_empty() {
+        let items: Vec<ListItem> = preview
+            .sources
+            .iter()
+            .map(|source| {
+                let (status, color) = match &source.resolved {
+                    Some(path) if path.exists() => (path.display().to_string(), theme.success),
+                    Some(path) => (format!("{} (missing)", path.display()), theme.error),
+                    None => ("could not be resolved".to_string(), theme.warning),
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("line {:<4} ", source.line), Style::default().fg(theme.muted)),
+                    Span::raw(format!("{}  → ", source.target)),
+                    Span::styled(status, Style::default().fg(color)),
+                ]))
+            })
+            .collect();
+        let sources = List::new(items)
+            .style(theme.text())
+            .block(Block::default().title(format!("Sourced files ({})", preview.sources.len())).borders(Borders::ALL));
+        f.render_widget(sources, chunks[2]);
+    }
+
+    let back = keys.hints(keymap::Screen::Preview, &[(&[Action::Back], "Back")]);
+    let help_text = if preview.error.is_some() {
+        back
+    } else {
+        format!("↑↓ PgUp/PgDn: Scroll | Home/End: Top/Bottom | {} | {}", keys.hints(keymap::Screen::Preview, &[(&[Action::Select], "Run")]), back)
+    };
+    let help = Paragraph::new(help_text)
+        .style(theme.text())
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL));
+    f.render_widget(help, chunks[3]);
+}
+
+fn draw_output_screen<B: Backend>(f: &mut Frame<B>, theme: &theme::Theme, view: &output_view::OutputView) {
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),
+            Constraint::Length(3),
+        ].as_ref())
+        .split(f.size());
+
+    let block = Block::default()
+        .title(format!("Output: {} ({} lines)", view.title, view.lines.len()))
+        .borders(Borders::ALL);
+    let inner = block.inner(chunks[0]);
+    view.viewport.set(inner.height as usize);
+
+    let query = view.query.to_lowercase();
+    let current = view.matches.get(view.current_match).copied();
+    let number_width = view.lines.len().to_string().len();
+    let lines: Vec<Line> = view.lines
+        .iter()
+        .enumerate()
+        .skip(view.scroll)
+        .take(inner.height as usize)
+        .map(|(i, text)| {
+            let mut spans = vec![Span::styled(format!("{:>width$} ", i + 1, width = number_width), Style::default().fg(theme.muted))];
+            if !query.is_empty() && text.to_lowercase().contains(&query) {
+                let style = if current == Some(i) {
+                    Style::default().fg(theme.background).bg(theme.warning).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme.warning).add_modifier(Modifier::BOLD)
+                };
+                spans.push(Span::styled(text.clone(), style));
+            } else {
+                spans.push(Span::raw(text.clone()));
+            }
+            Line::from(spans)
+        })
+        .collect();
+
+    let output = Paragraph::new(lines)
+        .block(block)
+        .style(theme.text());
+    f.render_widget(output, chunks[0]);
+
+    let footer = if view.searching {
+        format!("Search: {}█ | Enter: Done | Esc: Clear", view.query)
+    } else if !view.query.is_empty() {
+        format!("'{}': {} of {} matches | n/N: Next/Prev | /: New search | Esc: Back", view.query, if view.matches.is_empty() { 0 } else { view.current_match + 1 }, view.matches.len())
+    } else {
+        "↑↓ PgUp/PgDn Home/End: Scroll | /: Search | Esc: Back".to_string()
+    };
+    let footer = Paragraph::new(footer)
+        .style(theme.text())
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL));
+    f.render_widget(footer, chunks[1]);
+}
+
+fn get_random_quote() -> &'static str {
+    LINUX_QUOTES.choose(&mut rand::thread_rng()).unwrap_or(&"No quote available")
+}
+
+/// Resolves the program the current screen's selection refers to.
+fn selected_program_for(
+    menu_state: &MenuState,
+    categories: &[Category],
+    selected_category: usize,
+    selected_program: usize,
+    filtered_programs: &[search::SearchResult],
+    app_state: &AppState,
+) -> Option<Program> {
+    let program = match menu_state {
+        MenuState::Programs => categories.get(selected_category)?.programs.get(selected_program),
+        MenuState::Search => {
+            let result = filtered_programs.get(selected_program)?;
+            find_program_by_key(categories, &format!("{}/{}", result.category, result.name))
+        }
+        MenuState::History => find_program_by_key(categories, &app_state.history.get(app_state.history_selected)?.key),
+        MenuState::Preview => app_state.preview.as_ref().map(|preview| &preview.program),
+        _ => None,
+    };
+    program.cloned()
+}
+
+fn open_preview(program: Program, menu_state: &mut MenuState, app_state: &mut AppState) {
+    if *menu_state != MenuState::Preview {
+        app_state.preview_return = *menu_state;
+    }
+    app_state.preview = Some(preview::ScriptPreview::open(program));
+    *menu_state = MenuState::Preview;
+}
+
+/// Runs the program, unless it has never been run before: then its source is shown first and
+/// Enter on the preview starts it.
+fn run_or_preview(
+    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    program: Program,
+    menu_state: &mut MenuState,
+    app_state: &mut AppState,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if *menu_state == MenuState::Preview {
+        app_state.preview = None;
+        *menu_state = app_state.preview_return;
+    } else if !app_state.history.iter().any(|record| record.key == program.key()) {
+        open_preview(program, menu_state, app_state);
+        return Ok(());
+    }
+    launch_program(terminal, &program, app_state, false)
 }
 
-fn log_action(action: &str) {
-    use std::fs::OpenOptions;
-    use std::io::prelude::*;
+/// Starts a program's script in the embedded terminal pane, sized to fit the session screen.
+fn launch_program(
+    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    program: &Program,
+    app_state: &mut AppState,
+    allow_modified: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if let Err(e) = check_script(&program.script) {
+        record_run(program, Local::now(), None, false, Some(e.to_string()), None);
+        app_state.status_message = Some(format!("Error running script: {}", e));
+        logging::error("Script failed to start", json!({ "program": program.key(), "script": program.script, "error": e.to_string() }));
+        return Ok(());
+    }
 
-    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
-    let log_entry = format!("[{}] {}\n", timestamp, action);
+    app_state.allowed_modified = allow_modified.then(|| program.key());
+    if !verify_script(program, app_state) {
+        return Ok(());
+    }
 
-    if let Err(e) = OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(LOG_FILE)
-        .and_then(|mut file| file.write_all(log_entry.as_bytes()))
-    {
-        eprintln!("Failed to log action: {}", e);
+    let confirmed_in_queue = app_state
+        .queue
+        .as_ref()
+        .is_some_and(|queue| queue.current().is_some_and(|p| p.key() == program.key()) && queue.confirmed.contains(&program.key()));
+    if program.risk == RiskLevel::Destructive && !confirmed_in_queue {
+        app_state.risk_confirm = Some(RiskConfirm::new(program.clone(), false));
+        return Ok(());
     }
+    prepare_session(terminal, program, app_state)
 }
 
-fn load_color_scheme() -> ColorScheme {
-    match std::fs::File::open(COLOR_SCHEME_FILE) {
-        Ok(mut file) => {
-            let mut contents = String::new();
-            if file.read_to_string(&mut contents).is_ok() {
-                serde_json::from_str(&contents).unwrap_or(ColorScheme::Default)
-            } else {
-                ColorScheme::Default
+/// Checks the script and the files it sources against their pins. False when it must not start:
+/// the checksum warning is open or the files cannot be read.
+fn verify_script(program: &Program, app_state: &mut AppState) -> bool {
+    let allow_modified = app_state.allowed_modified.as_ref().is_some_and(|key| *key == program.key());
+    match integrity::verify(program) {
+        Ok(integrity::Integrity::Mismatch { file, expected, actual }) => {
+            logging::warn("Script checksum mismatch", json!({ "program": program.key(), "file": file, "expected": expected, "actual": actual, "allowed": allow_modified }));
+            if !allow_modified {
+                app_state.pending_run = Some(PendingRun { program: program.clone(), file, expected, actual });
+                return false;
             }
+            true
         }
-        Err(_) => ColorScheme::Default,
+        Ok(_) => true,
+        Err(e) => {
+            record_run(program, Local::now(), None, false, Some(e.to_string()), None);
+            app_state.status_message = Some(format!("Cannot verify script: {}", e));
+            logging::error("Script checksum failed", json!({ "program": program.key(), "script": program.script, "error": e.to_string() }));
+            false
+        }
+    }
+}
+
+/// Asks for the program's parameters if it has any, otherwise starts it.
+fn prepare_session(
+    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    program: &Program,
+    app_state: &mut AppState,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if !program.params.is_empty() {
+        app_state.param_form = Some(params::ParamForm::new(program.clone()));
+        return Ok(());
     }
+    spawn_session(terminal, program, params::Invocation::default(), None, app_state)
 }
 
-fn save_color_scheme(scheme: &ColorScheme) -> std::io::Result<()> {
-    let json = serde_json::to_string(scheme)?;
-    let mut file = std::fs::File::create(COLOR_SCHEME_FILE)?;
-    file.write_all(json.as_bytes())?;
+/// Starts the script in the embedded terminal pane once every check has passed. A program that
+/// needs root gets the sudo password just entered, or the one its queue was started with.
+fn spawn_session(
+    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    program: &Program,
+    mut invocation: params::Invocation,
+    sudo_password: Option<privilege::Secret>,
+    app_state: &mut AppState,
+) -> Result<(), Box<dyn std::error::Error>> {
+    // Checked again because the confirmation, the form and the password prompt leave time for
+    // the files to change since launch_program looked
+    if !verify_script(program, app_state) {
+        return Ok(());
+    }
+    let sudo_password = sudo_password.or_else(|| app_state.queue.as_mut().and_then(|queue| queue.sudo_password_for(program)));
+    match sudo_password {
+        Some(password) => privilege::prepare(&mut invocation, password),
+        None if privilege::wants_password(&app_state.sudo_settings, [program]) => {
+            app_state.sudo_prompt = Some(privilege::SudoPrompt::new(Some((program.clone(), invocation))));
+            return Ok(());
+        }
+        None => {}
+    }
+    app_state.allowed_modified = None;
+    let size = terminal.size()?;
+    let pane = session_pane_area(size);
+    match session::ScriptSession::spawn(program, &invocation, pane.height, pane.width) {
+        Ok(session) => {
+            let params: Vec<&str> = program.params.iter().map(|param| param.name.as_str()).collect();
+            logging::info("Script started", json!({ "program": program.key(), "script": program.script, "params": params }));
+            app_state.session = Some(session);
+        }
+        Err(e) => {
+            record_run(program, Local::now(), None, false, Some(e.to_string()), None);
+            app_state.status_message = Some(format!("Error running script: {}", e));
+            logging::error("Script failed to start", json!({ "program": program.key(), "script": program.script, "error": e.to_string() }));
+        }
+    }
     Ok(())
 }
 
-fn get_system_info() -> String {
-    let mut sys = System::new_all();
-    sys.refresh_all();
+/// Records a session that just exited and refreshes everything derived from the run history.
+fn finish_session(
+    app_state: &mut AppState,
+    categories: &mut Vec<Category>,
+    selected_category: &mut usize,
+    category_state: &mut ListState,
+) {
+    let Some(session) = &app_state.session else { return };
+    let Some(exit) = &session.exit else { return };
+
+    let program = &session.program;
+    record_run(program, session.started_at, exit.exit_code, exit.success, None, session.output.clone());
+    if let Some(queue) = app_state.queue.as_mut().filter(|queue| queue.current().is_some_and(|p| p.key() == program.key())) {
+        queue.finish(if exit.success {
+            queue::Outcome::Succeeded { duration: session.elapsed() }
+        } else {
+            queue::Outcome::Failed { exit_code: exit.exit_code, error: None }
+        });
+    }
+    app_state.status_message = Some(if exit.success {
+        "Script executed successfully".to_string()
+    } else {
+        format!("Script failed (exit {})", exit.exit_code.map_or("unknown".to_string(), |c| c.to_string()))
+    });
+    let level = if exit.success { logging::Level::Info } else { logging::Level::Warn };
+    logging::log(level, "Script finished", json!({
+        "program": program.key(),
+        "script": program.script,
+        "exit_code": exit.exit_code,
+        "duration_ms": session.elapsed().num_milliseconds(),
+    }));
+
+    app_state.history = state::load_history();
+    app_state.history_selected = 0;
+
+    // Recent may have just appeared above the catalog, so keep the same category selected
+    let current_category = categories.get(*selected_category).map(|c| c.name.clone());
+    refresh_recent_category(categories, &app_state.history);
+    *selected_category = current_category
+        .and_then(|name| categories.iter().position(|c| c.name == name))
+        .unwrap_or(0);
+    category_state.select(Some(*selected_category));
+}
 
-    let cpu_info = sys.cpus().first().map(|cpu| cpu.brand()).unwrap_or("Unknown CPU");
-    let total_memory = sys.total_memory() / 1024 / 1024; // Convert to MB
-    let used_memory = sys.used_memory() / 1024 / 1024; // Convert to MB
-    let gpu_info = "GPU info not available"; // sysinfo doesn't provide GPU info easily
+/// Starts queued programs until one is running or waiting at the checksum warning. When the
+/// queue is paused or done, its summary screen is shown instead.
+fn advance_queue(
+    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    menu_state: &mut MenuState,
+    app_state: &mut AppState,
+) -> Result<(), Box<dyn std::error::Error>> {
+    while !is_busy(app_state) {
+        let Some(queue) = app_state.queue.as_mut() else { return Ok(()) };
+        let Some(program) = queue.start_next() else {
+            if queue.is_done() {
+                let (succeeded, failed, skipped) = queue.counts();
+                logging::info("Queue finished", json!({ "succeeded": succeeded, "failed": failed, "skipped": skipped }));
+            }
+            *menu_state = MenuState::Queue;
+            return Ok(());
+        };
+        launch_program(terminal, &program, app_state, false)?;
+        fail_queued_start(app_state, &program);
+    }
+    Ok(())
+}
 
-    let os_info = if let Some(os_version) = sys.long_os_version() {
-        os_version
-    } else {
-        sys.name().unwrap_or_else(|| "Unknown OS".to_string())
-    };
+/// A script is running or a modal is waiting for the user.
+fn is_busy(app_state: &AppState) -> bool {
+    app_state.session.is_some()
+        || app_state.pending_run.is_some()
+        || app_state.risk_confirm.is_some()
+        || app_state.param_form.is_some()
+        || app_state.sudo_prompt.is_some()
+}
 
-    format!(
-        "OS: {}\nCPU: {}\nMemory: {} MB / {} MB\nGPU: {}",
-        os_info, cpu_info, used_memory, total_memory, gpu_info
-    )
+/// Starts running the programs one after another.
+fn start_queue(
+    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    menu_state: &mut MenuState,
+    app_state: &mut AppState,
+    programs: Vec<Program>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    app_state.queue = Some(queue::RunQueue::new(programs, app_state.queue_on_failure));
+    prepare_queue(terminal, menu_state, app_state)
 }
 
-fn draw_system_info_screen<B: Backend>(f: &mut Frame<B>, color_scheme: &ColorScheme, system_info: &str) {
-    let (bg_color, fg_color, _highlight_color) = color_scheme.get_colors();
-    
-    let system_info_lines: Vec<Line> = system_info
-        .lines()
-        .map(|line| {
-            let parts: Vec<&str> = line.splitn(2, ": ").collect();
-            if parts.len() == 2 {
-                Line::from(vec![
-                    Span::styled(format!("{}: ", parts[0]), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-                    Span::raw(parts[1]),
-                ])
-            } else {
-                Line::from(line)
-            }
-        })
-        .collect();
+/// Asks everything a new queue needs up front, so it can then run unattended: the name of each
+/// destructive program, then the sudo password if any program needs root.
+fn prepare_queue(
+    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    menu_state: &mut MenuState,
+    app_state: &mut AppState,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let Some(queue) = app_state.queue.as_ref() else { return Ok(()) };
+    if let Some(program) = queue.items.iter().find(|p| p.risk == RiskLevel::Destructive && !queue.confirmed.contains(&p.key())) {
+        app_state.risk_confirm = Some(RiskConfirm::new(program.clone(), true));
+        return Ok(());
+    }
+    if queue.sudo_password.is_none() && privilege::wants_password(&app_state.sudo_settings, &queue.items) {
+        app_state.sudo_prompt = Some(privilege::SudoPrompt::new(None));
+    }
+    advance_queue(terminal, menu_state, app_state)
+}
 
-    let mut text = vec![
-        Line::from(vec![
-            Span::styled("System Information", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
-        ]),
-        Line::from(""),
-    ];
-    text.extend(system_info_lines);
-    text.push(Line::from(""));
-    text.push(Line::from(vec![
-        Span::raw("Press "),
-        Span::styled("'i'", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-        Span::raw(" or "),
-        Span::styled("Esc", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-        Span::raw(" to return to the main menu"),
-    ]));
+/// Counts a queued program that could not be started as failed.
+fn fail_queued_start(app_state: &mut AppState, program: &Program) {
+    if is_busy(app_state) {
+        return;
+    }
+    let error = app_state.status_message.clone();
+    if let Some(queue) = app_state.queue.as_mut().filter(|queue| queue.current().is_some_and(|p| p.key() == program.key())) {
+        queue.finish(queue::Outcome::Failed { exit_code: None, error });
+    }
+}
 
-    let system_info_paragraph = Paragraph::new(text)
-        .block(Block::default().title("System Info").borders(Borders::ALL))
-        .style(Style::default().fg(fg_color).bg(bg_color))
-        .alignment(Alignment::Left)
-        .wrap(Wrap { trim: true });
+fn session_layout(area: Rect) -> std::rc::Rc<[Rect]> {
+    Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),  // Context
+            Constraint::Min(3),     // Terminal pane
+            Constraint::Length(3),  // Status banner
+        ].as_ref())
+        .split(area)
+}
 
-    let area = centered_rect(60, 40, f.size());
-    f.render_widget(Clear, area);
-    f.render_widget(system_info_paragraph, area);
+/// The inside of the terminal pane's border, which is what the script sees as its terminal.
+fn session_pane_area(area: Rect) -> Rect {
+    Block::default().borders(Borders::ALL).inner(session_layout(area)[1])
 }
 
-fn get_random_quote() -> &'static str {
-    LINUX_QUOTES.choose(&mut rand::thread_rng()).unwrap_or(&"No quote available")
+fn draw_session_screen<B: Backend>(f: &mut Frame<B>, theme: &theme::Theme, session: &session::ScriptSession, queue: Option<&queue::RunQueue>) {
+    let chunks = session_layout(f.size());
+    let program = &session.program;
+
+    let (state_text, state_color) = match &session.exit {
+        None => ("Running".to_string(), theme.warning),
+        Some(exit) if exit.success => ("Finished".to_string(), theme.success),
+        Some(exit) => (format!("Failed (exit {})", exit.exit_code.map_or("?".to_string(), |c| c.to_string())), theme.error),
+    };
+    let mut context = Vec::new();
+    if let Some(queue) = queue {
+        let number = queue.items.iter().position(|p| p.key() == program.key()).map_or(queue.position(), |index| index + 1);
+        context.push(Span::styled(format!("Queue {}/{} | ", number, queue.items.len()), Style::default().fg(theme.muted)));
+    }
+    context.extend([
+        Span::styled(program.category.clone(), Style::default().fg(theme.accent)),
+        Span::raw(" › "),
+        Span::styled(program.name.clone(), Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Span::raw(format!(" | {} | {} | ", program.script.display(), state::format_duration(session.elapsed()))),
+        Span::styled(state_text, Style::default().fg(state_color).add_modifier(Modifier::BOLD)),
+    ]);
+    let context = Paragraph::new(Line::from(context))
+    .alignment(Alignment::Center)
+    .block(Block::default().borders(Borders::ALL).border_style(theme.border_style()));
+    f.render_widget(context, chunks[0]);
+
+    let pane_block = Block::default()
+        .title(if session.is_scrolled() { "Output (scrolled back)" } else { "Output" })
+        .borders(Borders::ALL)
+        .border_style(theme.border_style());
+    let inner = pane_block.inner(chunks[1]);
+    session.resize(inner.height, inner.width);
+    let (lines, cursor) = session.render();
+    f.render_widget(Paragraph::new(lines).block(pane_block), chunks[1]);
+    if let Some((col, row)) = cursor {
+        f.set_cursor(inner.x + col, inner.y + row);
+    }
+
+    let banner = match &session.exit {
+        None => Paragraph::new("Input goes to the script | Shift+PgUp/PgDn or Mouse Wheel: Scroll")
+            .style(Style::default().fg(theme.foreground)),
+        Some(exit) if exit.success => Paragraph::new(format!(
+            "✔ Finished successfully in {} | Enter/Esc: Back to menu | PgUp/PgDn ↑↓: Scroll",
+            state::format_duration(session.elapsed())
+        ))
+        .style(Style::default().fg(theme.background).bg(theme.success).add_modifier(Modifier::BOLD)),
+        Some(exit) => Paragraph::new(format!(
+            "✘ Failed with exit code {} after {} | {} | PgUp/PgDn ↑↓: Scroll",
+            exit.exit_code.map_or("?".to_string(), |c| c.to_string()),
+            state::format_duration(session.elapsed()),
+            if queue.is_some_and(|queue| queue.paused) { "Queue paused, Enter/Esc: Queue summary" } else { "Enter/Esc: Back to menu" },
+        ))
+        .style(Style::default().fg(theme.background).bg(theme.error).add_modifier(Modifier::BOLD)),
+    };
+    f.render_widget(
+        banner
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).border_style(theme.border_style())),
+        chunks[2],
+    );
 }
 
 fn is_within_rect(x: u16, y: u16, rect: Rect) -> bool {
@@ -848,12 +2690,52 @@ fn is_within_rect(x: u16, y: u16, rect: Rect) -> bool {
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let options = match cli::take_global_options(&mut args) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, cli::USAGE);
+            std::process::exit(cli::EXIT_USAGE);
+        }
+    };
+    logging::init(options.log_level);
+    let settings = settings::load();
+    let update_source = update::UpdateSource::new(&settings.update, options.offline);
+
+    let layers = match config::discover_layers(options.config.as_deref()) {
+        Ok(layers) => layers,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(cli::EXIT_FAILURE);
+        }
+    };
+
+    match cli::parse_args(&args) {
+        Ok(Some(command)) => std::process::exit(cli::run(command, &layers, &update_source, &settings.sudo)),
+        Ok(None) => {}
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, cli::USAGE);
+            std::process::exit(cli::EXIT_USAGE);
+        }
+    }
+
+    let root_warning = match privilege::check_root(&settings.sudo) {
+        Ok(warning) => warning,
+        Err(reason) => {
+            eprintln!("{}", reason);
+            std::process::exit(cli::EXIT_FAILURE);
+        }
+    };
+
     println!("Starting program. Current version: {}", CURRENT_VERSION);
-    log_action("Program started");
+    logging::info("Program started", json!({ "version": CURRENT_VERSION }));
     
     let mut terminal = setup_terminal()?;
-    let config_path = PathBuf::from("config.toml");
-    let (mut categories, _config_dir) = load_config(&config_path)?;
+    let (mut categories, profiles) = load_catalog(&layers)?;
+    apply_favorites(&mut categories, &state::load_favorites());
+    refresh_favorites_category(&mut categories);
+    let history = state::load_history();
+    refresh_recent_category(&mut categories, &history);
 
     let mut selected_category = 0;
     let mut selected_program = 0;
@@ -863,46 +2745,90 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     program_state.select(Some(0));
     let mut menu_state = MenuState::Categories;
     let mut search_query = String::new();
-    let mut filtered_programs: Vec<(String, String, PathBuf)> = Vec::new();
+    let mut filtered_programs: Vec<search::SearchResult> = Vec::new();
+    // When and where the last left click on the programs list landed
+    let mut last_click: Option<(Instant, usize)> = None;
 
     let mut app_state = AppState {
-        status_message: None,
-        loading: true,
-        loading_progress: 0,
+        status_message: root_warning.map(str::to_string),
         system_info: get_system_info(),
         current_quote: get_random_quote().to_string(),
+        history,
+        history_selected: 0,
+        session: None,
+        output_view: None,
+        doctor_findings: Vec::new(),
+        doctor_selected: 0,
+        preview: None,
+        preview_return: MenuState::Categories,
+        pending_run: None,
+        allowed_modified: None,
+        risk_confirm: None,
+        param_form: None,
+        sudo_prompt: None,
+        sudo_settings: settings.sudo,
+        running_as_root: privilege::is_root(),
+        theme_selected: 0,
+        keymap: keymap::load(),
+        search_contents: false,
+        marked: Vec::new(),
+        queue: None,
+        queue_on_failure: settings.queue.on_failure,
+        profiles,
+        profile_selected: 0,
     };
 
-    // Simulate loading
-    terminal.draw(|f| {
-        draw_loading_animation(f, &ColorScheme::Default, app_state.loading_progress);
-    })?;
-
-    let update_available = match check_for_updates() {
-        Ok(update) => {
-            app_state.loading = false;
-            update
-        },
-        Err(e) => {
-            app_state.loading = false;
-            eprintln!("Error checking for updates: {}", e);
-            log_action(&format!("Error checking for updates: {}", e));
-            None
-        }
-    };
+    let mut update_check = update::spawn_check(&update_source);
+    let mut update_available = None;
 
-    let mut color_scheme = load_color_scheme();
+    let mut themes = theme::ThemeSet::load();
+    let mut exit_message = None;
 
     terminal.clear()?;
 
     loop {
+        if let Some(receiver) = &update_check {
+            match receiver.try_recv() {
+                Ok(release) => {
+                    update_available = release;
+                    update_check = None;
+                }
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => update_check = None,
+                Err(std::sync::mpsc::TryRecvError::Empty) => {}
+            }
+        }
+
         let categories_clone = categories.clone();
         terminal.draw(|f| {
             f.render_widget(Clear, f.size());
+
+            if let Some(session) = &app_state.session {
+                draw_session_screen(f, themes.current(), session, app_state.queue.as_ref());
+                return;
+            }
             
             match menu_state {
-                MenuState::Help => draw_help_screen(f, &color_scheme),
-                MenuState::SystemInfo => draw_system_info_screen(f, &color_scheme, &app_state.system_info),
+                MenuState::Help => draw_help_screen(f, themes.current(), &app_state.keymap),
+                MenuState::SystemInfo => draw_system_info_screen(f, themes.current(), &app_state.keymap, &app_state.system_info),
+                MenuState::History => draw_history_screen(f, themes.current(), &app_state.keymap, &app_state.history, app_state.history_selected),
+                MenuState::Doctor => draw_doctor_screen(f, themes.current(), &app_state),
+                MenuState::Themes => draw_theme_picker(f, &themes, &app_state.keymap, app_state.theme_selected),
+                MenuState::Profiles => draw_profiles_screen(f, themes.current(), &app_state.keymap, &app_state.profiles, &categories_clone, app_state.profile_selected),
+                MenuState::Queue => {
+                    if let Some(queue) = &app_state.queue {
+                        draw_queue_screen(f, themes.current(), &app_state.keymap, queue);
+                    }
+                }
+                MenuState::Preview => {
+                    if let Some(preview) = &app_state.preview {
+                        draw_preview_screen(f, themes.current(), &app_state.keymap, preview);
+                    }
+                }
+                MenuState::Output => {
+                    if let Some(view) = &app_state.output_view {
+                        draw_output_screen(f, themes.current(), view);
+                    }
+                }
                 _ => draw_ui(
                     f,
                     &categories_clone,
@@ -913,13 +2839,233 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     &search_query,
                     &filtered_programs,
                     &update_available,
-                    &color_scheme,
+                    themes.current(),
                     &app_state,
                 ),
             }
+
+            if let Some(pending) = &app_state.pending_run {
+                draw_modified_warning(f, themes.current(), &app_state.keymap, pending);
+            }
+            if let Some(confirm) = &app_state.risk_confirm {
+                draw_risk_confirm(f, themes.current(), &app_state.keymap, confirm);
+            }
+            if let Some(form) = &app_state.param_form {
+                draw_param_form(f, themes.current(), &app_state.keymap, form);
+            }
+            if let Some(prompt) = &app_state.sudo_prompt {
+                draw_sudo_prompt(f, themes.current(), &app_state.keymap, prompt, app_state.queue.as_ref());
+            }
         })?;
 
-        if let Event::Key(key) = event::read()? {
+        // While a script runs, poll so its output keeps streaming into the pane
+        if let Some(session) = app_state.session.as_mut() {
+            if session.poll_exit() {
+                finish_session(&mut app_state, &mut categories, &mut selected_category, &mut category_state);
+                if app_state.queue.is_some() {
+                    menu_state = MenuState::Queue;
+                    // A paused queue leaves the failed run on screen until the user closes it
+                    if !app_state.queue.as_ref().is_some_and(|queue| queue.paused) {
+                        app_state.session = None;
+                        advance_queue(&mut terminal, &mut menu_state, &mut app_state)?;
+                    }
+                    continue;
+                }
+            }
+            if event::poll(Duration::from_millis(SESSION_REFRESH_MS))? {
+                let Some(session) = app_state.session.as_mut() else { continue };
+                match event::read()? {
+                    Event::Key(key) if session.handle_key(key) => {
+                        app_state.session = None;
+                        terminal.clear()?;
+                    }
+                    Event::Mouse(mouse_event) => match mouse_event.kind {
+                        MouseEventKind::ScrollUp => session.scroll_up(3),
+                        MouseEventKind::ScrollDown => session.scroll_down(3),
+                        _ => {}
+                    },
+                    _ => {}
+                }
+            }
+            continue;
+        }
+
+        // Keep redrawing until the update check reports, so its result shows up without a key press
+        if update_check.is_some() && !event::poll(Duration::from_millis(UPDATE_POLL_MS))? {
+            continue;
+        }
+        let event = event::read()?;
+        // So is the typed confirmation for destructive programs
+        if let Some(mut confirm) = app_state.risk_confirm.take() {
+            let Event::Key(key) = event else {
+                app_state.risk_confirm = Some(confirm);
+                continue;
+            };
+            match app_state.keymap.action(keymap::Screen::Confirm, &key) {
+                Some(Action::Select) if confirm.typed.trim() == confirm.program.name => {
+                    logging::warn("Destructive program confirmed", json!({ "program": confirm.program.key() }));
+                    if confirm.queued {
+                        if let Some(queue) = app_state.queue.as_mut() {
+                            queue.confirmed.insert(confirm.program.key());
+                        }
+                        prepare_queue(&mut terminal, &mut menu_state, &mut app_state)?;
+                    } else {
+                        prepare_session(&mut terminal, &confirm.program, &mut app_state)?;
+                        fail_queued_start(&mut app_state, &confirm.program);
+                        advance_queue(&mut terminal, &mut menu_state, &mut app_state)?;
+                    }
+                    continue;
+                }
+                Some(Action::Select) => confirm.error = Some(format!("Type {} exactly to run it", confirm.program.name)),
+                Some(Action::Back) => {
+                    if confirm.queued {
+                        app_state.status_message = Some("Queue cancelled".to_string());
+                        if let Some(queue) = app_state.queue.as_mut() {
+                            queue.stop();
+                        }
+                    } else {
+                        app_state.status_message = Some(format!("Did not run {}", confirm.program.name));
+                        if let Some(queue) = app_state.queue.as_mut().filter(|queue| queue.current().is_some_and(|p| p.key() == confirm.program.key())) {
+                            queue.finish(queue::Outcome::Skipped);
+                        }
+                    }
+                    advance_queue(&mut terminal, &mut menu_state, &mut app_state)?;
+                    continue;
+                }
+                _ => match key.code {
+                    KeyCode::Char(c) => {
+                        confirm.error = None;
+                        confirm.typed.push(c);
+                    }
+                    KeyCode::Backspace => {
+                        confirm.typed.pop();
+                    }
+                    _ => {}
+                },
+            }
+            app_state.risk_confirm = Some(confirm);
+            continue;
+        }
+
+        // So is the sudo password prompt
+        if let Some(mut prompt) = app_state.sudo_prompt.take() {
+            let Event::Key(key) = event else {
+                app_state.sudo_prompt = Some(prompt);
+                continue;
+            };
+            match app_state.keymap.action(keymap::Screen::Sudo, &key) {
+                Some(Action::Select) => match privilege::authenticate(&prompt.password) {
+                    Ok(()) => {
+                        match prompt.pending {
+                            Some((program, invocation)) => {
+                                spawn_session(&mut terminal, &program, invocation, Some(prompt.password), &mut app_state)?;
+                                fail_queued_start(&mut app_state, &program);
+                            }
+                            None => {
+                                if let Some(queue) = app_state.queue.as_mut() {
+                                    queue.sudo_password = Some(prompt.password);
+                                }
+                            }
+                        }
+                        advance_queue(&mut terminal, &mut menu_state, &mut app_state)?;
+                        continue;
+                    }
+                    Err(e) => {
+                        prompt.password = privilege::Secret::default();
+                        prompt.error = Some(e);
+                    }
+                },
+                Some(Action::Back) => {
+                    match &prompt.pending {
+                        Some((program, _)) => {
+                            app_state.status_message = Some(format!("Did not run {}", program.name));
+                            if let Some(queue) = app_state.queue.as_mut().filter(|queue| queue.current().is_some_and(|p| p.key() == program.key())) {
+                                queue.finish(queue::Outcome::Skipped);
+                            }
+                        }
+                        None => {
+                            app_state.status_message = Some("Queue cancelled".to_string());
+                            if let Some(queue) = app_state.queue.as_mut() {
+                                queue.stop();
+                            }
+                        }
+                    }
+                    advance_queue(&mut terminal, &mut menu_state, &mut app_state)?;
+                    continue;
+                }
+                _ => match key.code {
+                    KeyCode::Char(c) => {
+                        prompt.error = None;
+                        prompt.password.push(c);
+                    }
+                    KeyCode::Backspace => {
+                        prompt.password.pop();
+                    }
+                    _ => {}
+                },
+            }
+            app_state.sudo_prompt = Some(prompt);
+            continue;
+        }
+
+        // So is the parameter form, until it is submitted or cancelled
+        if let Some(mut form) = app_state.param_form.take() {
+            let Event::Key(key) = event else {
+                app_state.param_form = Some(form);
+                continue;
+            };
+            match app_state.keymap.action(keymap::Screen::Form, &key) {
+                Some(Action::Up) => form.previous(),
+                Some(Action::Down) => form.next(),
+                Some(Action::Select) => {
+                    if let Some(values) = form.submit() {
+                        let invocation = params::Invocation::new(&form.program.params, &values);
+                        spawn_session(&mut terminal, &form.program, invocation, None, &mut app_state)?;
+                        fail_queued_start(&mut app_state, &form.program);
+                        advance_queue(&mut terminal, &mut menu_state, &mut app_state)?;
+                        continue;
+                    }
+                }
+                Some(Action::Back) => {
+                    app_state.status_message = Some(format!("Did not run {}", form.program.name));
+                    if let Some(queue) = app_state.queue.as_mut().filter(|queue| queue.current().is_some_and(|p| p.key() == form.program.key())) {
+                        queue.finish(queue::Outcome::Skipped);
+                    }
+                    advance_queue(&mut terminal, &mut menu_state, &mut app_state)?;
+                    continue;
+                }
+                _ => match key.code {
+                    KeyCode::Char(c) => form.type_char(c),
+                    KeyCode::Backspace => form.backspace(),
+                    KeyCode::Left => form.cycle(false),
+                    KeyCode::Right => form.cycle(true),
+                    _ => {}
+                },
+            }
+            app_state.param_form = Some(form);
+            continue;
+        }
+
+        // The checksum warning is modal: only its answer is accepted
+        if let Some(pending) = app_state.pending_run.take() {
+            match event {
+                Event::Key(key) if app_state.keymap.action(keymap::Screen::Global, &key) == Some(Action::Yes) => {
+                    launch_program(&mut terminal, &pending.program, &mut app_state, true)?;
+                    fail_queued_start(&mut app_state, &pending.program);
+                }
+                Event::Key(_) => {
+                    app_state.status_message = Some(format!("Did not run modified script {}", pending.file.display()));
+                    if let Some(queue) = app_state.queue.as_mut().filter(|queue| queue.current().is_some_and(|p| p.key() == pending.program.key())) {
+                        queue.finish(queue::Outcome::Skipped);
+                    }
+                }
+                _ => app_state.pending_run = Some(pending),
+            }
+            advance_queue(&mut terminal, &mut menu_state, &mut app_state)?;
+            continue;
+        }
+
+        if let Event::Key(key) = event {
             let (action, menu_state_changed) = handle_input(
                 key,
                 &mut menu_state,
@@ -930,59 +3076,84 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 &mut filtered_programs,
                 &mut category_state,
                 &mut program_state,
-                &mut color_scheme,
+                &mut themes,
                 &mut app_state,
             );
 
             match action {
                 InputAction::Quit => {
-                    log_action("Program exited");
+                    logging::info("Program exited", json!({}));
                     break;
                 }
-                InputAction::RunScript => {
-                    let script = match menu_state {
-                        MenuState::Programs => &categories[selected_category].programs[selected_program].script,
-                        MenuState::Search => &filtered_programs[selected_program].2,
-                        _ => continue,
+                InputAction::InstallUpdate => {
+                    let Some(release) = &update_available else {
+                        app_state.status_message = Some(format!("v{} is the latest version", CURRENT_VERSION));
+                        continue;
                     };
-
-                    app_state.loading = true;
-                    app_state.loading_progress = 0;
-                    let loading_thread = std::thread::spawn(move || {
-                        for i in 0..4 {
-                            std::thread::sleep(Duration::from_millis(500));
-                            app_state.loading_progress = i;
+                    app_state.status_message = Some(format!("Downloading {}...", release.tag_name));
+                    terminal.draw(|f| draw_ui(f, &categories, selected_category, &mut category_state, &mut program_state, &menu_state, &search_query, &filtered_programs, &update_available, themes.current(), &app_state))?;
+                    match update::install(release, true) {
+                        Ok(path) => {
+                            // Scripts extracted for this version were removed, so start over with the new binary
+                            exit_message = Some(format!("Updated {} to {}. Start linuxtoolbox again to use it.", path.display(), release.tag_name));
+                            break;
                         }
-                    });
-
-                    match run_script(script) {
-                        Ok(_) => {
-                            app_state.status_message = Some("Script executed successfully".to_string());
-                            log_action(&format!("Script executed: {:?}", script));
-                        },
                         Err(e) => {
-                            app_state.status_message = Some(format!("Error running script: {}", e));
-                            log_action(&format!("Error running script: {:?} - {}", script, e));
-                            disable_raw_mode()?;
-                            execute!(terminal.backend_mut(), LeaveAlternateScreen, Show)?;
-                            println!("Error running script: {}", e);
-                            println!("Press any key to continue...");
-                            let _ = event::read()?;
+                            logging::error("Update failed", json!({ "tag": release.tag_name, "error": e.to_string() }));
+                            app_state.status_message = Some(format!("Update failed: {}", e));
                         }
                     }
+                }
+                InputAction::RunScript => {
+                    let Some(program) = selected_program_for(&menu_state, &categories, selected_category, selected_program, &filtered_programs, &app_state) else {
+                        app_state.status_message = Some("That program is no longer in the catalog".to_string());
+                        continue;
+                    };
 
-                    loading_thread.join().unwrap();
-                    app_state.loading = false;
-
-                    enable_raw_mode()?;
-                    execute!(terminal.backend_mut(), EnterAlternateScreen, Hide)?;
-                    terminal.clear()?;
+                    run_or_preview(&mut terminal, program, &mut menu_state, &mut app_state)?;
+                }
+                InputAction::RunQueue => {
+                    // A queue left paused behind another screen is picked up where it was
+                    if app_state.queue.as_ref().is_some_and(|queue| !queue.is_done()) {
+                        menu_state = MenuState::Queue;
+                        continue;
+                    }
+                    let programs: Vec<Program> = app_state.marked.iter().filter_map(|key| find_program_by_key(&categories, key).cloned()).collect();
+                    if programs.is_empty() {
+                        let mark = app_state.keymap.keys(keymap::Screen::Programs, Action::Mark);
+                        app_state.status_message = Some(format!("Mark programs with {} first", mark));
+                        continue;
+                    }
+                    logging::info("Queue started", json!({ "programs": app_state.marked, "on_failure": app_state.queue_on_failure }));
+                    app_state.marked.clear();
+                    start_queue(&mut terminal, &mut menu_state, &mut app_state, programs)?;
+                }
+                InputAction::RunProfile => {
+                    if app_state.queue.as_ref().is_some_and(|queue| !queue.is_done()) {
+                        menu_state = MenuState::Queue;
+                        continue;
+                    }
+                    let Some(profile) = app_state.profiles.get(app_state.profile_selected) else { continue };
+                    let (programs, missing) = profile.resolve(&categories);
+                    if !missing.is_empty() {
+                        app_state.status_message = Some(format!("Profile {} names programs that are not in the catalog: {}", profile.name, missing.join(", ")));
+                        continue;
+                    }
+                    let programs: Vec<Program> = programs.into_iter().cloned().collect();
+                    logging::info("Profile started", json!({ "profile": profile.name, "programs": profile.programs, "on_failure": app_state.queue_on_failure }));
+                    start_queue(&mut terminal, &mut menu_state, &mut app_state, programs)?;
+                }
+                InputAction::ContinueQueue => advance_queue(&mut terminal, &mut menu_state, &mut app_state)?,
+                InputAction::PreviewScript => {
+                    if let Some(program) = selected_program_for(&menu_state, &categories, selected_category, selected_program, &filtered_programs, &app_state) {
+                        open_preview(program, &mut menu_state, &mut app_state);
+                    }
                 }
                 InputAction::ConfirmQuit => {
                     // Do nothing here, wait for next input
                 }
                 InputAction::Continue => {
-                    if app_state.status_message == Some("Press 'y' to confirm quit, any other key to cancel".to_string()) {
+                    if app_state.status_message == Some(quit_prompt(&app_state.keymap)) {
                         app_state.status_message = Some("Quit cancelled".to_string());
                     }
                     // Refresh the quote when the menu state changes
@@ -991,9 +3162,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     }
                 }
             }
-        } else if let Event::Mouse(mouse_event) = event::read()? {
+        } else if let Event::Mouse(mouse_event) = event {
             match mouse_event.kind {
-                MouseEventKind::Down(_) => {
+                // Only the menu screens use this layout. Full-screen views, the preview included, must
+                // not turn a stray click into a run
+                MouseEventKind::Down(_) if matches!(menu_state, MenuState::Categories | MenuState::Programs | MenuState::Search) => {
                     let mouse_x = mouse_event.column;
                     let mouse_y = mouse_event.row;
 
@@ -1021,9 +3194,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                     // Handle clicks in categories list
                     if is_within_rect(mouse_x, mouse_y, main_chunks[0]) {
-                        let relative_y = mouse_y - main_chunks[0].y - 1; // -1 for border
-                        if relative_y < categories.len() as u16 {
-                            selected_category = relative_y as usize;
+                        // The top border row is not an item
+                        let clicked = mouse_y.checked_sub(main_chunks[0].y + 1).map(|row| usize::from(row) + category_state.offset());
+                        last_click = None;
+                        if let Some(clicked) = clicked.filter(|&index| index < categories.len()) {
+                            selected_category = clicked;
                             category_state.select(Some(selected_category));
                             if menu_state == MenuState::Categories {
                                 menu_state = MenuState::Programs;
@@ -1034,35 +3209,30 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     }
                     // Handle clicks in programs list
                     else if is_within_rect(mouse_x, mouse_y, main_chunks[1]) {
-                        let relative_y = mouse_y - main_chunks[1].y - 1; // -1 for border
-                        let programs = if menu_state == MenuState::Search {
-                            filtered_programs.len()
-                        } else {
-                            categories[selected_category].programs.len()
+                        // Rows count from the first item scrolled into view
+                        let offset = program_state.offset();
+                        let relative_y = mouse_y.checked_sub(main_chunks[1].y + 1).map(usize::from);
+                        let clicked = match relative_y {
+                            Some(row) if menu_state == MenuState::Search => {
+                                filtered_programs.get(offset..).and_then(|shown| search_result_at(shown, row)).map(|index| index + offset)
+                            }
+                            row => row.map(|row| row + offset).filter(|&index| index < categories[selected_category].programs.len()),
+                        };
+
+                        // A click selects the program, a second one on it soon after runs it like Enter
+                        let double_click = clicked.is_some()
+                            && last_click.is_some_and(|(at, index)| Some(index) == clicked && at.elapsed() <= Duration::from_millis(DOUBLE_CLICK_MS));
+                        last_click = match (mouse_event.kind, clicked) {
+                            (MouseEventKind::Down(event::MouseButton::Left), Some(index)) if !double_click => Some((Instant::now(), index)),
+                            _ => None,
                         };
-                        
-                        if relative_y < programs as u16 {
-                            selected_program = relative_y as usize;
+                        if let Some(clicked) = clicked {
+                            selected_program = clicked;
                             program_state.select(Some(selected_program));
-                            
-                            // Double click to run program
-                            if mouse_event.kind == MouseEventKind::Down(event::MouseButton::Left) {
-                                let script = match menu_state {
-                                    MenuState::Programs => &categories[selected_category].programs[selected_program].script,
-                                    MenuState::Search => &filtered_programs[selected_program].2,
-                                    _ => continue,
-                                };
-                                
-                                // Run the script
-                                match run_script(script) {
-                                    Ok(_) => {
-                                        app_state.status_message = Some("Script executed successfully".to_string());
-                                        log_action(&format!("Script executed: {:?}", script));
-                                    },
-                                    Err(e) => {
-                                        app_state.status_message = Some(format!("Error running script: {}", e));
-                                        log_action(&format!("Error running script: {:?} - {}", script, e));
-                                    }
+
+                            if double_click && mouse_event.kind == MouseEventKind::Down(event::MouseButton::Left) {
+                                if let Some(program) = selected_program_for(&menu_state, &categories, selected_category, selected_program, &filtered_programs, &app_state) {
+                                    run_or_preview(&mut terminal, program, &mut menu_state, &mut app_state)?;
                                 }
                             }
                         }
@@ -1070,11 +3240,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 },
                 MouseEventKind::ScrollDown => {
                     match menu_state {
-                        MenuState::Categories => {
-                            if selected_category < categories.len() - 1 {
-                                selected_category += 1;
-                                category_state.select(Some(selected_category));
-                            }
+                        MenuState::Categories if selected_category + 1 < categories.len() => {
+                            selected_category += 1;
+                            category_state.select(Some(selected_category));
                         },
                         MenuState::Programs => {
                             let max_programs = categories[selected_category].programs.len();
@@ -1083,28 +3251,22 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 program_state.select(Some(selected_program));
                             }
                         },
-                        MenuState::Search => {
-                            if selected_program < filtered_programs.len() - 1 {
-                                selected_program += 1;
-                                program_state.select(Some(selected_program));
-                            }
+                        MenuState::Search if selected_program + 1 < filtered_programs.len() => {
+                            selected_program += 1;
+                            program_state.select(Some(selected_program));
                         },
                         _ => {}
                     }
                 },
                 MouseEventKind::ScrollUp => {
                     match menu_state {
-                        MenuState::Categories => {
-                            if selected_category > 0 {
-                                selected_category -= 1;
-                                category_state.select(Some(selected_category));
-                            }
+                        MenuState::Categories if selected_category > 0 => {
+                            selected_category -= 1;
+                            category_state.select(Some(selected_category));
                         },
-                        MenuState::Programs | MenuState::Search => {
-                            if selected_program > 0 {
-                                selected_program -= 1;
-                                program_state.select(Some(selected_program));
-                            }
+                        MenuState::Programs | MenuState::Search if selected_program > 0 => {
+                            selected_program -= 1;
+                            program_state.select(Some(selected_program));
                         },
                         _ => {}
                     }
@@ -1122,6 +3284,72 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         DisableMouseCapture,
         Show
     )?;
+    if let Some(message) = exit_message {
+        println!("{}", message);
+    }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn catalog() -> Vec<Category> {
+        let layer = config::CatalogLayer { source: config::CatalogSource::Bundled, path: PathBuf::from("/opt/toolbox/config.toml") };
+        let program = |category: &str, name: &str| {
+            Program::from_entry(category, name, ProgramEntry::Path(format!("scripts/{}.sh", name)), &layer).unwrap()
+        };
+        vec![
+            Category { name: "Gaming".to_string(), programs: vec![program("Gaming", "Steam"), program("Gaming", "Lutris")], kind: CategoryKind::Catalog },
+            // Pseudo-categories hold copies and must not be where a profile finds its programs
+            Category { name: FAVORITES_CATEGORY.to_string(), programs: vec![program("Gaming", "Steam")], kind: CategoryKind::Favorites },
+        ]
+    }
+
+    fn profiles(text: &str) -> Result<Vec<Profile>, String> {
+        let config: Value = toml::from_str(text).unwrap();
+        parse_profiles(&config[PROFILES_TABLE])
+    }
+
+    #[test]
+    fn parse_profiles_reads_lists_and_tables() {
+        let profiles = profiles(
+            r#"
+            [profiles]
+            gaming = ["Gaming/Steam", "Gaming/Lutris"]
+            ai-box = { description = "AI", programs = ["AI/Ollama"] }
+            "#,
+        )
+        .unwrap();
+        let gaming = profiles.iter().find(|p| p.name == "gaming").unwrap();
+        assert_eq!(gaming.programs, ["Gaming/Steam", "Gaming/Lutris"]);
+        assert_eq!(gaming.description, None);
+        let ai = profiles.iter().find(|p| p.name == "ai-box").unwrap();
+        assert_eq!(ai.description.as_deref(), Some("AI"));
+    }
+
+    #[test]
+    fn parse_profiles_refuses_programs() {
+        let error = profiles("[profiles]\nSteam = \"scripts/steam.sh\"\n").err().unwrap();
+        assert!(error.contains("profiles.Steam looks like a program"), "{}", error);
+        let error = profiles("[profiles]\nSteam = { script = \"scripts/steam.sh\" }\n").err().unwrap();
+        assert!(error.contains("reserved"), "{}", error);
+        assert!(profiles("[profiles]\nbroken = { programs = \"Gaming/Steam\" }\n").is_err());
+        assert!(profiles("profiles = 1\n").is_err());
+    }
+
+    #[test]
+    fn profile_resolves_programs_in_order_and_reports_missing_ones() {
+        let categories = catalog();
+        let profile = Profile {
+            name: "gaming".to_string(),
+            description: None,
+            programs: vec!["Gaming/Lutris".to_string(), "Gaming/Heroic".to_string(), "Gaming/Steam".to_string(), "Favorites/Steam".to_string()],
+        };
+        let (found, missing) = profile.resolve(&categories);
+        let found: Vec<String> = found.iter().map(|program| program.key()).collect();
+        assert_eq!(found, ["Gaming/Lutris", "Gaming/Steam"]);
+        assert_eq!(missing, ["Gaming/Heroic", "Favorites/Steam"]);
+    }
+}
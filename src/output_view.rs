@@ -0,0 +1,185 @@
+use std::cell::Cell;
+use std::path::Path;
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+/// Scrollable, searchable view of one run's saved output.
+pub struct OutputView {
+    pub title: String,
+    pub lines: Vec<String>,
+    pub scroll: usize,
+    pub query: String,
+    pub searching: bool,
+    pub matches: Vec<usize>,
+    pub current_match: usize,
+    /// Visible height from the last draw, used for paging
+    pub viewport: Cell<usize>,
+}
+
+impl OutputView {
+    pub fn open(title: String, path: &Path) -> std::io::Result<Self> {
+        let raw = std::fs::read(path)?;
+        Ok(OutputView {
+            title,
+            lines: strip_terminal_codes(&String::from_utf8_lossy(&raw)),
+            scroll: 0,
+            query: String::new(),
+            searching: false,
+            matches: Vec::new(),
+            current_match: 0,
+            viewport: Cell::new(20),
+        })
+    }
+
+    fn max_scroll(&self) -> usize {
+        self.lines.len().saturating_sub(self.viewport.get())
+    }
+
+    fn scroll_to(&mut self, line: usize) {
+        self.scroll = line.min(self.max_scroll());
+    }
+
+    fn update_matches(&mut self) {
+        let query = self.query.to_lowercase();
+        self.matches = if query.is_empty() {
+            Vec::new()
+        } else {
+            self.lines
+                .iter()
+                .enumerate()
+                .filter(|(_, line)| line.to_lowercase().contains(&query))
+                .map(|(i, _)| i)
+                .collect()
+        };
+        self.current_match = self.matches.iter().position(|&line| line >= self.scroll).unwrap_or(0);
+        self.jump_to_current_match();
+    }
+
+    fn jump_to_current_match(&mut self) {
+        if let Some(&line) = self.matches.get(self.current_match) {
+            // Keep a little context above the hit
+            self.scroll_to(line.saturating_sub(2));
+        }
+    }
+
+    /// Handles a key. Returns true when the viewer should be closed.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if self.searching {
+            match key.code {
+                KeyCode::Enter => self.searching = false,
+                KeyCode::Esc => {
+                    self.searching = false;
+                    self.query.clear();
+                    self.matches.clear();
+                }
+                KeyCode::Backspace => {
+                    self.query.pop();
+                    self.update_matches();
+                }
+                KeyCode::Char(c) => {
+                    self.query.push(c);
+                    self.update_matches();
+                }
+                _ => {}
+            }
+            return false;
+        }
+
+        let page = self.viewport.get().max(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => return true,
+            KeyCode::Up | KeyCode::Char('k') => self.scroll_to(self.scroll.saturating_sub(1)),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll_to(self.scroll + 1),
+            KeyCode::PageUp => self.scroll_to(self.scroll.saturating_sub(page)),
+            KeyCode::PageDown | KeyCode::Char(' ') => self.scroll_to(self.scroll + page),
+            KeyCode::Home | KeyCode::Char('g') => self.scroll_to(0),
+            KeyCode::End | KeyCode::Char('G') => self.scroll_to(usize::MAX),
+            KeyCode::Char('/') => {
+                self.searching = true;
+                self.query.clear();
+                self.matches.clear();
+            }
+            KeyCode::Char('n') if !self.matches.is_empty() => {
+                self.current_match = (self.current_match + 1) % self.matches.len();
+                self.jump_to_current_match();
+            }
+            KeyCode::Char('N') if !self.matches.is_empty() => {
+                self.current_match = (self.current_match + self.matches.len() - 1) % self.matches.len();
+                self.jump_to_current_match();
+            }
+            _ => {}
+        }
+        false
+    }
+}
+
+/// Turns raw terminal output into plain lines: escape sequences are dropped, and a carriage return
+/// starts the line over the way a progress bar redraws itself.
+pub fn strip_terminal_codes(raw: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut chars = raw.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\x1b' => match chars.next() {
+                // CSI: parameters up to a final byte in @..~
+                Some('[') => {
+                    for c in chars.by_ref() {
+                        if ('@'..='~').contains(&c) {
+                            break;
+                        }
+                    }
+                }
+                // OSC: terminated by BEL or ESC \
+                Some(']') => {
+                    while let Some(c) = chars.next() {
+                        if c == '\x07' || (c == '\x1b' && chars.next_if_eq(&'\\').is_some()) {
+                            break;
+                        }
+                    }
+                }
+                // Charset selection and similar take one more character
+                Some('(') | Some(')') | Some('#') => {
+                    chars.next();
+                }
+                _ => {}
+            },
+            '\n' => lines.push(std::mem::take(&mut line)),
+            '\r' => {
+                if chars.peek() != Some(&'\n') {
+                    line.clear();
+                }
+            }
+            '\x08' => {
+                line.pop();
+            }
+            '\t' => line.push_str("    "),
+            c if c.is_control() => {}
+            c => line.push(c),
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip_terminal_codes_drops_escape_sequences() {
+        let raw = "\x1b[1;32mok\x1b[0m done\r\n\x1b]0;title\x07next\x1b]8;;link\x1b\\ line\n\x1b(Bcharset\n";
+        assert_eq!(strip_terminal_codes(raw), ["ok done", "next line", "charset"]);
+    }
+
+    #[test]
+    fn strip_terminal_codes_redraws_lines_like_a_terminal() {
+        // A progress bar overwrites itself, backspace erases and tabs become spaces
+        let raw = "progress 10%\rprogress 100%\nab\x08c\n\tindented\x07\nno newline";
+        assert_eq!(strip_terminal_codes(raw), ["progress 100%", "ac", "    indented", "no newline"]);
+        assert!(strip_terminal_codes("").is_empty());
+    }
+}
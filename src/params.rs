@@ -0,0 +1,371 @@
+use std::collections::{BTreeMap, HashMap};
+use std::ffi::OsString;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::privilege::Secret;
+use crate::{logging, state, Program};
+
+const VALUES_FILE: &str = "params.json";
+/// `bash -c` program that runs the script named by $0 with the remaining arguments, so script
+/// paths with spaces and parameter values reach the script unchanged
+const RUN_SCRIPT: &str = "exec \"$0\" \"$@\"";
+
+#[derive(Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ParamType {
+    #[default]
+    String,
+    Integer,
+    Boolean,
+    Choice,
+}
+
+/// A parameter as written in config.toml.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ParamSpec {
+    name: String,
+    #[serde(rename = "type")]
+    kind: Option<ParamType>,
+    /// A string, number or boolean
+    default: Option<toml::Value>,
+    #[serde(default)]
+    choices: Vec<String>,
+    #[serde(default)]
+    secret: bool,
+    /// Pass the value in this environment variable instead of as an argument
+    env: Option<String>,
+    description: Option<String>,
+}
+
+/// A value the user is asked for before a program runs.
+#[derive(Clone)]
+pub struct Param {
+    pub name: String,
+    pub kind: ParamType,
+    pub default: Option<String>,
+    pub choices: Vec<String>,
+    /// Hidden while typed and never remembered
+    pub secret: bool,
+    pub env: Option<String>,
+    pub description: Option<String>,
+}
+
+impl Param {
+    /// Checks the declaration. Giving `choices` without a type makes it a choice.
+    pub fn from_spec(spec: ParamSpec) -> Result<Self, String> {
+        // Arguments can be read by any user from /proc/<pid>/cmdline
+        if spec.secret && spec.env.is_none() {
+            return Err(format!("parameter '{}' is secret, so it must be passed in an `env` variable", spec.name));
+        }
+        let kind = spec.kind.unwrap_or(if spec.choices.is_empty() { ParamType::String } else { ParamType::Choice });
+        if kind == ParamType::Choice && spec.choices.is_empty() {
+            return Err(format!("parameter '{}' is a choice but lists no choices", spec.name));
+        }
+        if kind != ParamType::Choice && !spec.choices.is_empty() {
+            return Err(format!("parameter '{}' lists choices but is not a choice", spec.name));
+        }
+        let default = match spec.default {
+            None => None,
+            Some(toml::Value::String(value)) => Some(value),
+            Some(toml::Value::Integer(value)) => Some(value.to_string()),
+            Some(toml::Value::Boolean(value)) => Some(value.to_string()),
+            Some(other) => return Err(format!("parameter '{}' has a {} default", spec.name, other.type_str())),
+        };
+        let param = Param {
+            name: spec.name,
+            kind,
+            default: None,
+            choices: spec.choices,
+            secret: spec.secret,
+            env: spec.env,
+            description: spec.description,
+        };
+        let default = default.map(|value| param.validate(&value)).transpose().map_err(|e| format!("default of {}", e))?;
+        Ok(Param { default, ..param })
+    }
+
+    /// Checks a value against the type and returns it in normal form, e.g. "yes" becomes "true".
+    pub fn validate(&self, value: &str) -> Result<String, String> {
+        let value = value.trim();
+        match self.kind {
+            ParamType::String => Ok(value.to_string()),
+            ParamType::Integer => value
+                .parse::<i64>()
+                .map(|n| n.to_string())
+                .map_err(|_| format!("'{}' must be a whole number", self.name)),
+            ParamType::Boolean => match value.to_ascii_lowercase().as_str() {
+                "true" | "yes" | "y" | "1" | "on" => Ok("true".to_string()),
+                "false" | "no" | "n" | "0" | "off" => Ok("false".to_string()),
+                _ => Err(format!("'{}' must be true or false", self.name)),
+            },
+            ParamType::Choice => self
+                .choices
+                .iter()
+                .find(|choice| choice.eq_ignore_ascii_case(value))
+                .cloned()
+                .ok_or_else(|| format!("'{}' must be one of {}", self.name, self.choices.join(", "))),
+        }
+    }
+
+    /// What the form starts with when nothing was remembered.
+    fn initial_value(&self) -> String {
+        match (&self.default, self.kind) {
+            (Some(default), _) => default.clone(),
+            (None, ParamType::Boolean) => "false".to_string(),
+            (None, ParamType::Choice) => self.choices[0].clone(),
+            (None, _) => String::new(),
+        }
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        match self.kind {
+            ParamType::String => "text",
+            ParamType::Integer => "number",
+            ParamType::Boolean => "yes/no",
+            ParamType::Choice => "choice",
+        }
+    }
+}
+
+/// Arguments and environment variables a script is started with.
+#[derive(Default)]
+pub struct Invocation {
+    pub args: Vec<String>,
+    pub env: Vec<(String, String)>,
+    /// Shell commands run in the script's session just before it starts
+    pub prelude: Option<&'static str>,
+    /// A line typed into the script's terminal, without echo, for the prelude to read
+    pub input: Option<Secret>,
+}
+
+impl Invocation {
+    /// Values of parameters with `env` go into the environment, the rest become arguments in
+    /// declaration order.
+    pub fn new(params: &[Param], values: &HashMap<String, String>) -> Self {
+        let mut invocation = Invocation::default();
+        for param in params {
+            let value = values.get(&param.name).cloned().unwrap_or_default();
+            match &param.env {
+                Some(variable) => invocation.env.push((variable.clone(), value)),
+                None => invocation.args.push(value),
+            }
+        }
+        invocation
+    }
+
+    /// Arguments for `bash` that run the script with this invocation's arguments.
+    pub fn bash_args(&self, script: &Path) -> Vec<OsString> {
+        let program = match self.prelude {
+            Some(prelude) => format!("{}\n{}", prelude, RUN_SCRIPT),
+            None => RUN_SCRIPT.to_string(),
+        };
+        let mut args: Vec<OsString> = vec!["-c".into(), program.into(), script.into()];
+        args.extend(self.args.iter().map(OsString::from));
+        args
+    }
+}
+
+type RememberedValues = BTreeMap<String, BTreeMap<String, String>>;
+
+fn load_all() -> RememberedValues {
+    fs::read_to_string(state::state_dir().join(VALUES_FILE))
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Values entered last time for a program's parameters, by parameter name.
+pub fn load_values(program_key: &str) -> HashMap<String, String> {
+    load_all().remove(program_key).unwrap_or_default().into_iter().collect()
+}
+
+/// Remembers the values for next time, leaving out secret parameters.
+pub fn save_values(program_key: &str, params: &[Param], values: &HashMap<String, String>) -> std::io::Result<()> {
+    let mut all = load_all();
+    let remembered: BTreeMap<String, String> = params
+        .iter()
+        .filter(|param| !param.secret)
+        .filter_map(|param| Some((param.name.clone(), values.get(&param.name)?.clone())))
+        .collect();
+    all.insert(program_key.to_string(), remembered);
+    let dir = state::state_dir();
+    fs::create_dir_all(&dir)?;
+    fs::write(dir.join(VALUES_FILE), serde_json::to_string_pretty(&all)?)
+}
+
+/// Fills in every parameter from `given`, then remembered values, then defaults. Fails on an
+/// unknown name, an invalid value or a secret that was not given and has no default.
+pub fn resolve_values(program_key: &str, params: &[Param], given: &HashMap<String, String>) -> Result<HashMap<String, String>, String> {
+    if let Some(unknown) = given.keys().find(|name| !params.iter().any(|param| param.name == **name)) {
+        return Err(format!("unknown parameter '{}'", unknown));
+    }
+    let remembered = load_values(program_key);
+    let mut values = HashMap::new();
+    for param in params {
+        if param.secret && param.default.is_none() && !given.contains_key(&param.name) {
+            return Err(format!("secret parameter '{}' needs a value, pass {}=<value>", param.name, param.name));
+        }
+        let value = given
+            .get(&param.name)
+            .or_else(|| remembered.get(&param.name).filter(|_| !param.secret))
+            .cloned()
+            .unwrap_or_else(|| param.initial_value());
+        values.insert(param.name.clone(), param.validate(&value)?);
+    }
+    Ok(values)
+}
+
+/// The form shown before a program with parameters starts.
+pub struct ParamForm {
+    pub program: Program,
+    /// Text of each field, in the order of `program.params`
+    pub values: Vec<String>,
+    pub selected: usize,
+    pub error: Option<String>,
+}
+
+impl ParamForm {
+    pub fn new(program: Program) -> Self {
+        let remembered = load_values(&program.key());
+        let values = program
+            .params
+            .iter()
+            .map(|param| {
+                remembered
+                    .get(&param.name)
+                    .filter(|value| !param.secret && param.validate(value).is_ok())
+                    .cloned()
+                    .unwrap_or_else(|| param.initial_value())
+            })
+            .collect();
+        ParamForm { program, values, selected: 0, error: None }
+    }
+
+    pub fn previous(&mut self) {
+        self.selected = self.selected.checked_sub(1).unwrap_or(self.program.params.len() - 1);
+    }
+
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1) % self.program.params.len();
+    }
+
+    /// Types into text and number fields; Space flips yes/no fields.
+    pub fn type_char(&mut self, c: char) {
+        self.error = None;
+        match self.program.params[self.selected].kind {
+            ParamType::String | ParamType::Integer => self.values[self.selected].push(c),
+            ParamType::Boolean | ParamType::Choice if c == ' ' => self.cycle(true),
+            _ => {}
+        }
+    }
+
+    pub fn backspace(&mut self) {
+        self.error = None;
+        if matches!(self.program.params[self.selected].kind, ParamType::String | ParamType::Integer) {
+            self.values[self.selected].pop();
+        }
+    }
+
+    /// Steps yes/no and choice fields through their values.
+    pub fn cycle(&mut self, forward: bool) {
+        let param = &self.program.params[self.selected];
+        let options: Vec<String> = match param.kind {
+            ParamType::Boolean => vec!["true".to_string(), "false".to_string()],
+            ParamType::Choice => param.choices.clone(),
+            _ => return,
+        };
+        let current = options.iter().position(|option| *option == self.values[self.selected]).unwrap_or(0);
+        let index = if forward { (current + 1) % options.len() } else { (current + options.len() - 1) % options.len() };
+        self.values[self.selected] = options[index].clone();
+    }
+
+    /// Validates every field, moving to the first bad one. On success the values are remembered.
+    pub fn submit(&mut self) -> Option<HashMap<String, String>> {
+        let mut values = HashMap::new();
+        for (index, param) in self.program.params.iter().enumerate() {
+            match param.validate(&self.values[index]) {
+                Ok(value) => {
+                    values.insert(param.name.clone(), value);
+                }
+                Err(e) => {
+                    self.selected = index;
+                    self.error = Some(e);
+                    return None;
+                }
+            }
+        }
+        if let Err(e) = save_values(&self.program.key(), &self.program.params, &values) {
+            logging::warn("Failed to remember parameter values", json!({ "program": self.program.key(), "error": e.to_string() }));
+        }
+        Some(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn param(spec: &str) -> Result<Param, String> {
+        Param::from_spec(toml::from_str(spec).expect("valid parameter spec"))
+    }
+
+    #[test]
+    fn validate_normalises_integers() {
+        let port = param("name = \"port\"\ntype = \"integer\"").unwrap();
+        assert_eq!(port.validate(" 51820 "), Ok("51820".to_string()));
+        assert_eq!(port.validate("-1"), Ok("-1".to_string()));
+        assert!(port.validate("4.5").is_err());
+        assert!(port.validate("").is_err());
+    }
+
+    #[test]
+    fn validate_normalises_booleans() {
+        let flag = param("name = \"flag\"\ntype = \"boolean\"").unwrap();
+        for value in ["yes", "Y", "true", "1", "On"] {
+            assert_eq!(flag.validate(value), Ok("true".to_string()), "{}", value);
+        }
+        for value in ["no", "N", "FALSE", "0", "off"] {
+            assert_eq!(flag.validate(value), Ok("false".to_string()), "{}", value);
+        }
+        assert!(flag.validate("maybe").is_err());
+    }
+
+    #[test]
+    fn validate_matches_choices_ignoring_case() {
+        let dns = param("name = \"dns\"\nchoices = [\"Cloudflare\", \"quad9\"]").unwrap();
+        assert!(dns.kind == ParamType::Choice);
+        assert_eq!(dns.validate("cloudflare"), Ok("Cloudflare".to_string()));
+        assert_eq!(dns.validate("QUAD9"), Ok("quad9".to_string()));
+        assert!(dns.validate("google").is_err());
+    }
+
+    #[test]
+    fn validate_trims_strings() {
+        let name = param("name = \"client\"").unwrap();
+        assert_eq!(name.validate("  laptop "), Ok("laptop".to_string()));
+        assert_eq!(name.validate(""), Ok(String::new()));
+    }
+
+    #[test]
+    fn resolve_values_requires_secrets() {
+        let params = vec![param("name = \"psk\"\nsecret = true\nenv = \"WG_PSK\"").unwrap()];
+        let error = resolve_values("Test/Secret", &params, &HashMap::new()).unwrap_err();
+        assert!(error.contains("'psk'"), "{}", error);
+        let given = HashMap::from([("psk".to_string(), "hunter2".to_string())]);
+        assert_eq!(resolve_values("Test/Secret", &params, &given).unwrap().get("psk").map(String::as_str), Some("hunter2"));
+    }
+
+    #[test]
+    fn from_spec_checks_defaults_and_secrets() {
+        assert_eq!(param("name = \"port\"\ntype = \"integer\"\ndefault = 80").unwrap().default, Some("80".to_string()));
+        assert!(param("name = \"port\"\ntype = \"integer\"\ndefault = \"eighty\"").is_err());
+        assert!(param("name = \"dns\"\ntype = \"choice\"").is_err());
+        assert!(param("name = \"psk\"\nsecret = true").is_err());
+        assert!(param("name = \"psk\"\nsecret = true\nenv = \"WG_PSK\"").is_ok());
+    }
+}
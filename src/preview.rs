@@ -0,0 +1,239 @@
+use std::cell::Cell;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local};
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+
+use crate::theme::Theme;
+use crate::Program;
+
+const KEYWORDS: &[&str] = &[
+    "if", "then", "else", "elif", "fi", "for", "while", "until", "do", "done", "case", "esac", "in",
+    "function", "return", "local", "export", "select", "break", "continue", "exit", "source", "set",
+];
+
+/// A file pulled in with `source` or `.`.
+pub struct SourcedFile {
+    /// 1-based line of the script that sources it
+    pub line: usize,
+    /// The argument as written in the script
+    pub target: String,
+    /// Where it points on disk, when that could be worked out
+    pub resolved: Option<PathBuf>,
+}
+
+/// Read-only view of a script's source, shown before running it.
+pub struct ScriptPreview {
+    pub program: Program,
+    /// Source lines, tabs expanded; highlighted when drawn so theme changes apply
+    pub lines: Vec<String>,
+    pub size: Option<u64>,
+    pub modified: Option<DateTime<Local>>,
+    pub sources: Vec<SourcedFile>,
+    /// Why the file could not be read, if it could not
+    pub error: Option<String>,
+    pub scroll: usize,
+    /// Visible height from the last draw, used for paging
+    pub viewport: Cell<usize>,
+}
+
+impl ScriptPreview {
+    pub fn open(program: Program) -> Self {
+        let metadata = std::fs::metadata(&program.script).ok();
+        let (lines, sources, error) = match std::fs::read(&program.script) {
+            Ok(raw) => {
+                let text = String::from_utf8_lossy(&raw).replace('\t', "    ");
+                let sources = find_sources(&text, &program.script);
+                (text.lines().map(str::to_string).collect(), sources, None)
+            }
+            Err(e) => (Vec::new(), Vec::new(), Some(e.to_string())),
+        };
+
+        ScriptPreview {
+            size: metadata.as_ref().map(|m| m.len()),
+            modified: metadata.and_then(|m| m.modified().ok()).map(DateTime::<Local>::from),
+            program,
+            lines,
+            sources,
+            error,
+            scroll: 0,
+            viewport: Cell::new(20),
+        }
+    }
+
+    fn scroll_to(&mut self, line: usize) {
+        self.scroll = line.min(self.lines.len().saturating_sub(self.viewport.get()));
+    }
+
+    /// Handles a scrolling or closing key. Returns true when the preview should be closed.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let page = self.viewport.get().max(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Backspace => return true,
+            KeyCode::Up | KeyCode::Char('k') => self.scroll_to(self.scroll.saturating_sub(1)),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll_to(self.scroll + 1),
+            KeyCode::PageUp => self.scroll_to(self.scroll.saturating_sub(page)),
+            KeyCode::PageDown | KeyCode::Char(' ') => self.scroll_to(self.scroll + page),
+            KeyCode::Home | KeyCode::Char('g') => self.scroll_to(0),
+            KeyCode::End | KeyCode::Char('G') => self.scroll_to(usize::MAX),
+            _ => {}
+        }
+        false
+    }
+}
+
+/// Finds `source FILE` and `. FILE` lines. Variables set to the script's own directory, like
+/// `DIR="$(dirname "$0")"`, are substituted so the helpers under functions/ resolve.
+pub fn find_sources(text: &str, script: &Path) -> Vec<SourcedFile> {
+    let script_dir = script.parent().unwrap_or(Path::new("."));
+    let script_dir = script_dir.to_string_lossy();
+    let dir_expressions = ["$(dirname \"$0\")", "$(dirname $0)", "$(dirname \"${BASH_SOURCE[0]}\")", "${BASH_SOURCE%/*}"];
+
+    let mut dir_variables = Vec::new();
+    for line in text.lines() {
+        let Some((name, value)) = line.trim().trim_start_matches("readonly ").split_once('=') else { continue };
+        let value = value.trim_matches('"');
+        let is_dir = dir_expressions.contains(&value)
+            || (value.starts_with("$(cd") && dir_expressions.iter().any(|expr| value.contains(expr)));
+        if is_dir && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            dir_variables.push(name.to_string());
+        }
+    }
+
+    let mut sources = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let trimmed = line.trim_start();
+        let Some(rest) = trimmed.strip_prefix("source ").or_else(|| trimmed.strip_prefix(". ")) else { continue };
+        let target = rest.split(['#', ';']).next().unwrap_or("").trim().to_string();
+        if target.is_empty() {
+            continue;
+        }
+
+        let mut expanded = target.clone();
+        for expr in dir_expressions {
+            expanded = expanded.replace(expr, &script_dir);
+        }
+        for name in &dir_variables {
+            expanded = expanded.replace(&format!("${{{}}}", name), &script_dir).replace(&format!("${}", name), &script_dir);
+        }
+        let expanded = expanded.replace(['"', '\''], "");
+        let resolved = (!expanded.contains('$')).then(|| PathBuf::from(expanded));
+
+        sources.push(SourcedFile { line: index + 1, target, resolved });
+    }
+    sources
+}
+
+/// A small bash highlighter: comments, quoted strings, variables and keywords.
+pub fn highlight_line(line: &str, theme: &Theme) -> Line<'static> {
+    let comment = Style::default().fg(theme.muted).add_modifier(Modifier::ITALIC);
+    let string = Style::default().fg(theme.success);
+    let variable = Style::default().fg(theme.accent);
+    let keyword = Style::default().fg(theme.title).add_modifier(Modifier::BOLD);
+
+    let chars: Vec<char> = line.chars().collect();
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let mut i = 0;
+    let flush = |plain: &mut String, spans: &mut Vec<Span<'static>>| {
+        if !plain.is_empty() {
+            spans.push(Span::raw(std::mem::take(plain)));
+        }
+    };
+
+    while i < chars.len() {
+        let c = chars[i];
+        let at_word_start = i == 0 || !(chars[i - 1].is_alphanumeric() || chars[i - 1] == '_' || chars[i - 1] == '$');
+        match c {
+            '#' if i == 0 || chars[i - 1].is_whitespace() => {
+                flush(&mut plain, &mut spans);
+                spans.push(Span::styled(chars[i..].iter().collect::<String>(), comment));
+                i = chars.len();
+            }
+            '\'' | '"' => {
+                flush(&mut plain, &mut spans);
+                let mut end = i + 1;
+                while end < chars.len() && chars[end] != c {
+                    if c == '"' && chars[end] == '\\' {
+                        end += 1;
+                    }
+                    end += 1;
+                }
+                let end = (end + 1).min(chars.len());
+                spans.push(Span::styled(chars[i..end].iter().collect::<String>(), string));
+                i = end;
+            }
+            '$' if i + 1 < chars.len() => {
+                flush(&mut plain, &mut spans);
+                let end = match chars[i + 1] {
+                    '{' => chars[i..].iter().position(|&c| c == '}').map_or(chars.len(), |p| i + p + 1),
+                    c if c.is_alphanumeric() || c == '_' => {
+                        i + 1 + chars[i + 1..].iter().take_while(|c| c.is_alphanumeric() || **c == '_').count()
+                    }
+                    '?' | '#' | '@' | '*' | '$' | '!' => i + 2,
+                    _ => i + 1,
+                };
+                spans.push(Span::styled(chars[i..end].iter().collect::<String>(), variable));
+                i = end;
+            }
+            c if at_word_start && c.is_alphabetic() => {
+                let end = i + chars[i..].iter().take_while(|c| c.is_alphanumeric() || **c == '_' || **c == '-').count();
+                let word: String = chars[i..end].iter().collect();
+                if KEYWORDS.contains(&word.as_str()) {
+                    flush(&mut plain, &mut spans);
+                    spans.push(Span::styled(word, keyword));
+                } else {
+                    plain.push_str(&word);
+                }
+                i = end;
+            }
+            c => {
+                plain.push(c);
+                i += 1;
+            }
+        }
+    }
+    flush(&mut plain, &mut spans);
+    Line::from(spans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolved(text: &str) -> Vec<(usize, Option<PathBuf>)> {
+        find_sources(text, Path::new("/opt/toolbox/scripts/tool.sh")).into_iter().map(|source| (source.line, source.resolved)).collect()
+    }
+
+    #[test]
+    fn find_sources_resolves_the_script_directory() {
+        let text = "#!/bin/bash\nsource \"$(dirname \"$0\")/functions/common.sh\"\n. ${BASH_SOURCE%/*}/functions/pkg.sh # helpers\n";
+        assert_eq!(
+            resolved(text),
+            vec![
+                (2, Some(PathBuf::from("/opt/toolbox/scripts/functions/common.sh"))),
+                (3, Some(PathBuf::from("/opt/toolbox/scripts/functions/pkg.sh"))),
+            ]
+        );
+    }
+
+    #[test]
+    fn find_sources_substitutes_directory_variables() {
+        let text = "readonly DIR=\"$(cd \"$(dirname \"$0\")\" && pwd)\"\nSCRIPT_DIR=$(dirname $0)\nsource \"${DIR}/a.sh\"; echo done\nsource $SCRIPT_DIR/b.sh\n";
+        assert_eq!(
+            resolved(text),
+            vec![(3, Some(PathBuf::from("/opt/toolbox/scripts/a.sh"))), (4, Some(PathBuf::from("/opt/toolbox/scripts/b.sh")))]
+        );
+    }
+
+    #[test]
+    fn find_sources_keeps_what_it_cannot_resolve() {
+        let sources = find_sources("source \"$HOME/.bashrc\"\n  . /etc/os-release\nsourced=1\n", Path::new("tool.sh"));
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].target, "\"$HOME/.bashrc\"");
+        assert_eq!(sources[0].resolved, None);
+        assert_eq!(sources[1].resolved, Some(PathBuf::from("/etc/os-release")));
+    }
+}
@@ -0,0 +1,215 @@
+use std::io::Write;
+use std::os::unix::fs::MetadataExt;
+use std::process::{Command, Stdio};
+
+use serde_json::json;
+
+use crate::params::Invocation;
+use crate::settings::{RootPolicy, SudoSettings};
+use crate::{logging, Program};
+
+/// Runs before a root script in its terminal. sudo keeps a timestamp per terminal session, so the
+/// session validates its own with the password typed into the terminal, then refreshes it every
+/// minute, well inside sudo's default 5 minute timeout, for as long as it lasts. The password stays in a shell variable that is never
+/// exported and goes away when the script replaces the shell.
+///
+/// The script keeps the shell's pid when it replaces it, where an EXIT trap would never run, so
+/// the refresher is handed that pid and stops within a second of the script exiting.
+const SESSION_PRELUDE: &str = "\
+IFS= read -r password
+stty echo
+printf '%s\\n' \"$password\" | sudo -S -v -p '' 2>/dev/null
+unset password
+(exec bash -c 'while kill -0 \"$1\" 2>/dev/null; do sleep 1; if ((SECONDS >= 60)); then SECONDS=0; sudo -n -v || exit; fi; done' keepalive $$ \
+  </dev/null >/dev/null 2>&1) &";
+
+pub const ROOT_WARNING: &str = "Running as root: scripts get full privileges and files they create in your home belong to root";
+pub const ROOT_REFUSAL: &str = "Refusing to run as root. Start linuxtoolbox as your normal user, scripts use sudo where they need it, \
+or set as_root = \"warn\" under [sudo] in settings.toml";
+
+/// Whether the toolbox itself runs with root's user id.
+pub fn is_root() -> bool {
+    // /proc/self belongs to the effective user of the process
+    std::fs::metadata("/proc/self").is_ok_and(|metadata| metadata.uid() == 0)
+}
+
+/// Applies the `as_root` setting. Ok holds a warning to show, Err a reason to stop.
+pub fn check_root(settings: &SudoSettings) -> Result<Option<&'static str>, &'static str> {
+    if !is_root() {
+        return Ok(None);
+    }
+    logging::warn("Started as root", json!({ "as_root": settings.as_root }));
+    match settings.as_root {
+        RootPolicy::Allow => Ok(None),
+        RootPolicy::Warn => Ok(Some(ROOT_WARNING)),
+        RootPolicy::Refuse => Err(ROOT_REFUSAL),
+    }
+}
+
+/// Whether any of the programs needs the sudo password asked for before it runs.
+pub fn wants_password<'a>(settings: &SudoSettings, programs: impl IntoIterator<Item = &'a Program>) -> bool {
+    settings.preauth && !is_root() && programs.into_iter().any(|program| program.requires_root) && sudo_needs_password()
+}
+
+/// False when sudo is missing, so scripts are left to do whatever they do without it, or when the
+/// user may run anything without a password.
+fn sudo_needs_password() -> bool {
+    // -k makes sudo ignore a cached timestamp for this one check without removing it
+    Command::new("sudo")
+        .args(["-n", "-k", "true"])
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| !status.success())
+}
+
+/// Asks for the password on the toolbox's own terminal without echoing it, for the command line.
+pub fn read_password(prompt: &str) -> std::io::Result<Secret> {
+    eprint!("{}", prompt);
+    let _echo = HiddenInput::start();
+    let mut password = Secret::default();
+    // Room for any sane password, as with Secret, so no copies are left behind in reallocations
+    let mut line = Vec::with_capacity(256);
+    // One byte at a time, so what is typed after the line is left for the script
+    let mut byte = [0u8; 1];
+    loop {
+        // SAFETY: reads at most one byte into a one byte buffer
+        match unsafe { libc::read(libc::STDIN_FILENO, byte.as_mut_ptr().cast(), 1) } {
+            0 => break,
+            n if n < 0 => {
+                let error = std::io::Error::last_os_error();
+                if error.kind() != std::io::ErrorKind::Interrupted {
+                    line.fill(0);
+                    return Err(error);
+                }
+            }
+            _ if byte[0] == b'\n' => break,
+            _ => line.push(byte[0]),
+        }
+    }
+    eprintln!();
+    for c in String::from_utf8_lossy(&line).trim_end_matches('\r').chars() {
+        password.push(c);
+    }
+    line.fill(0);
+    std::hint::black_box(&line);
+    if password.char_count() == 0 {
+        return Err(std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "no password given"));
+    }
+    Ok(password)
+}
+
+/// Echo turned off on stdin, when it is a terminal, until dropped.
+struct HiddenInput(Option<libc::termios>);
+
+impl HiddenInput {
+    fn start() -> Self {
+        // SAFETY: termios is only read after tcgetattr filled it in
+        unsafe {
+            let mut termios = std::mem::MaybeUninit::<libc::termios>::uninit();
+            if libc::tcgetattr(libc::STDIN_FILENO, termios.as_mut_ptr()) != 0 {
+                return HiddenInput(None);
+            }
+            let saved = termios.assume_init();
+            let mut hidden = saved;
+            hidden.c_lflag &= !libc::ECHO;
+            libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &hidden);
+            HiddenInput(Some(saved))
+        }
+    }
+}
+
+impl Drop for HiddenInput {
+    fn drop(&mut self) {
+        if let Some(saved) = &self.0 {
+            // SAFETY: restores settings tcgetattr returned for the same descriptor
+            unsafe { libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, saved) };
+        }
+    }
+}
+
+/// A password that is overwritten with zeros when dropped.
+#[derive(Clone)]
+pub struct Secret(String);
+
+impl Default for Secret {
+    fn default() -> Self {
+        // Room for any sane password, so typing it never leaves copies behind in reallocations
+        Secret(String::with_capacity(256))
+    }
+}
+
+impl Secret {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.0.push(c);
+    }
+
+    pub fn pop(&mut self) {
+        self.0.pop();
+    }
+
+    pub fn char_count(&self) -> usize {
+        self.0.chars().count()
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        let mut bytes = std::mem::take(&mut self.0).into_bytes();
+        bytes.fill(0);
+        // Keeps the zeroing from being optimised away as a write to memory about to be freed
+        std::hint::black_box(&bytes);
+    }
+}
+
+/// Checks the password with sudo on the toolbox's own terminal.
+pub fn authenticate(password: &Secret) -> Result<(), String> {
+    let mut child = Command::new("sudo")
+        .args(["-S", "-v", "-p", ""])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Cannot run sudo: {}", e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // sudo gives up when it reads end of file instead of another attempt
+        let _ = writeln!(stdin, "{}", password.as_str());
+    }
+    let output = child.wait_with_output().map_err(|e| format!("Cannot run sudo: {}", e))?;
+    if output.status.success() {
+        logging::info("sudo authenticated", json!({}));
+        return Ok(());
+    }
+    let message = String::from_utf8_lossy(&output.stderr);
+    logging::warn("sudo authentication failed", json!({ "error": message.trim() }));
+    match message.lines().map(str::trim).find(|line| !line.is_empty() && !line.starts_with("Sorry, try again")) {
+        Some(line) if !line.contains("incorrect password") => Err(line.to_string()),
+        _ => Err("Wrong password, try again".to_string()),
+    }
+}
+
+/// Lets a root program's script start with a valid sudo timestamp. The password is typed into its
+/// terminal as it starts, and dropped with the invocation.
+pub fn prepare(invocation: &mut Invocation, password: Secret) {
+    invocation.input = Some(password);
+    invocation.prelude = Some(SESSION_PRELUDE);
+}
+
+/// The password modal shown before the first program that needs root.
+pub struct SudoPrompt {
+    /// The program to start once authenticated, with its arguments. None when asked before a queue.
+    pub pending: Option<(Program, Invocation)>,
+    pub password: Secret,
+    pub error: Option<String>,
+}
+
+impl SudoPrompt {
+    pub fn new(pending: Option<(Program, Invocation)>) -> Self {
+        SudoPrompt { pending, password: Secret::default(), error: None }
+    }
+}
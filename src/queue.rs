@@ -0,0 +1,209 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Local};
+
+use crate::privilege::Secret;
+use crate::settings::OnFailure;
+use crate::Program;
+
+/// How one queued program ended.
+pub enum Outcome {
+    Succeeded { duration: chrono::Duration },
+    Failed { exit_code: Option<i32>, error: Option<String> },
+    /// Never started: declined at the checksum warning, or left when the queue was stopped
+    Skipped,
+}
+
+/// Programs marked in the menu, run one after another in the order they were marked.
+pub struct RunQueue {
+    pub items: Vec<Program>,
+    /// One entry per finished item, in queue order
+    pub outcomes: Vec<Outcome>,
+    pub on_failure: OnFailure,
+    /// Waiting for the user after a failure
+    pub paused: bool,
+    /// Whether the item after the finished ones has been started
+    started: bool,
+    pub started_at: DateTime<Local>,
+    /// Keys of the destructive programs whose names were typed when the queue started
+    pub confirmed: HashSet<String>,
+    /// The sudo password asked for when the queue started, until the last program needing root starts
+    pub sudo_password: Option<Secret>,
+}
+
+impl RunQueue {
+    pub fn new(items: Vec<Program>, on_failure: OnFailure) -> Self {
+        RunQueue {
+            items,
+            outcomes: Vec::new(),
+            on_failure,
+            paused: false,
+            started: false,
+            started_at: Local::now(),
+            confirmed: HashSet::new(),
+            sudo_password: None,
+        }
+    }
+
+    /// The program to start next, marking it as started. None while paused, busy or done.
+    pub fn start_next(&mut self) -> Option<Program> {
+        if self.paused || self.started {
+            return None;
+        }
+        let program = self.items.get(self.outcomes.len())?.clone();
+        self.started = true;
+        Some(program)
+    }
+
+    /// The program that was started and has not finished yet.
+    pub fn current(&self) -> Option<&Program> {
+        self.items.get(self.outcomes.len()).filter(|_| self.started)
+    }
+
+    /// Records how the current program ended, pausing the queue if it failed and that is configured.
+    pub fn finish(&mut self, outcome: Outcome) {
+        if matches!(outcome, Outcome::Failed { .. }) && self.on_failure == OnFailure::Pause && self.outcomes.len() + 1 < self.items.len() {
+            self.paused = true;
+        }
+        self.outcomes.push(outcome);
+        self.started = false;
+    }
+
+    /// The sudo password for the program being started, if it is the current one and needs root.
+    /// The queue keeps a copy only while a later program needs root too.
+    pub fn sudo_password_for(&mut self, program: &Program) -> Option<Secret> {
+        if !program.requires_root || self.current().is_none_or(|current| current.key() != program.key()) {
+            return None;
+        }
+        if self.items[self.outcomes.len() + 1..].iter().any(|p| p.requires_root) {
+            self.sudo_password.clone()
+        } else {
+            self.sudo_password.take()
+        }
+    }
+
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Gives up on everything that has not run yet.
+    pub fn stop(&mut self) {
+        while self.outcomes.len() < self.items.len() {
+            self.outcomes.push(Outcome::Skipped);
+        }
+        self.paused = false;
+        self.started = false;
+        self.sudo_password = None;
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.outcomes.len() == self.items.len()
+    }
+
+    /// 1-based position of the current or next item, for "2/5" style progress.
+    pub fn position(&self) -> usize {
+        (self.outcomes.len() + 1).min(self.items.len())
+    }
+
+    /// (succeeded, failed, skipped)
+    pub fn counts(&self) -> (usize, usize, usize) {
+        self.outcomes.iter().fold((0, 0, 0), |(ok, failed, skipped), outcome| match outcome {
+            Outcome::Succeeded { .. } => (ok + 1, failed, skipped),
+            Outcome::Failed { .. } => (ok, failed + 1, skipped),
+            Outcome::Skipped => (ok, failed, skipped + 1),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{CatalogLayer, CatalogSource};
+    use crate::ProgramEntry;
+
+    fn program(name: &str, requires_root: bool) -> Program {
+        let layer = CatalogLayer { source: CatalogSource::Bundled, path: "/opt/toolbox/config.toml".into() };
+        let mut program = Program::from_entry("Test", name, ProgramEntry::Path(format!("scripts/{}.sh", name)), &layer).unwrap();
+        program.requires_root = requires_root;
+        program
+    }
+
+    fn failed() -> Outcome {
+        Outcome::Failed { exit_code: Some(1), error: None }
+    }
+
+    fn password() -> Secret {
+        let mut secret = Secret::default();
+        "hunter2".chars().for_each(|c| secret.push(c));
+        secret
+    }
+
+    #[test]
+    fn finish_pauses_after_a_failure_with_more_to_run() {
+        let mut queue = RunQueue::new(vec![program("a", false), program("b", false), program("c", false)], OnFailure::Pause);
+        assert_eq!(queue.start_next().map(|p| p.name), Some("a".to_string()));
+        assert!(queue.start_next().is_none(), "only one program runs at a time");
+        queue.finish(failed());
+        assert!(queue.paused);
+        assert!(queue.start_next().is_none());
+
+        queue.resume();
+        assert_eq!(queue.start_next().map(|p| p.name), Some("b".to_string()));
+        queue.finish(Outcome::Succeeded { duration: chrono::Duration::seconds(1) });
+        assert_eq!(queue.position(), 3);
+        // Failing the last program leaves nothing to pause for
+        queue.start_next();
+        queue.finish(failed());
+        assert!(!queue.paused);
+        assert!(queue.is_done());
+        assert_eq!(queue.counts(), (1, 2, 0));
+    }
+
+    #[test]
+    fn finish_carries_on_when_configured_and_stop_skips_the_rest() {
+        let mut queue = RunQueue::new(vec![program("a", false), program("b", false), program("c", false)], OnFailure::Continue);
+        queue.start_next();
+        queue.finish(failed());
+        assert!(!queue.paused);
+        assert_eq!(queue.current().map(|p| p.name.as_str()), None);
+        assert_eq!(queue.start_next().map(|p| p.name), Some("b".to_string()));
+        queue.stop();
+        assert!(queue.is_done());
+        assert_eq!(queue.counts(), (0, 1, 2));
+    }
+
+    #[test]
+    fn sudo_password_is_kept_only_while_a_later_program_needs_root() {
+        let items = vec![program("root1", true), program("user", false), program("root2", true)];
+        let mut queue = RunQueue::new(items.clone(), OnFailure::Continue);
+        queue.sudo_password = Some(password());
+
+        // Only the program being started gets it
+        assert!(queue.sudo_password_for(&items[0]).is_none());
+        let first = queue.start_next().unwrap();
+        assert!(queue.sudo_password_for(&items[2]).is_none());
+        assert_eq!(queue.sudo_password_for(&first).map(|p| p.as_str().to_string()), Some("hunter2".to_string()));
+        assert!(queue.sudo_password.is_some());
+        queue.finish(Outcome::Succeeded { duration: chrono::Duration::zero() });
+
+        let second = queue.start_next().unwrap();
+        assert!(queue.sudo_password_for(&second).is_none());
+        queue.finish(Outcome::Succeeded { duration: chrono::Duration::zero() });
+
+        let last = queue.start_next().unwrap();
+        assert!(queue.sudo_password_for(&last).is_some());
+        assert!(queue.sudo_password.is_none());
+    }
+
+    #[test]
+    fn stop_forgets_the_sudo_password() {
+        let mut queue = RunQueue::new(vec![program("root1", true), program("root2", true)], OnFailure::Pause);
+        queue.sudo_password = Some(password());
+        queue.start_next();
+        queue.finish(failed());
+        assert!(queue.paused);
+        queue.stop();
+        assert!(queue.sudo_password.is_none());
+        assert!(!queue.paused);
+    }
+}
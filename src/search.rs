@@ -0,0 +1,377 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{preview, Category, CategoryKind, Program};
+
+/// Matching lines shown under each script contents hit
+const CONTEXT_LINES: usize = 3;
+
+/// Part of a program that a query can match, with how much a match there counts.
+#[derive(Clone, Copy, PartialEq)]
+enum Field {
+    Name,
+    Category,
+    Alias,
+    Tag,
+    Description,
+}
+
+impl Field {
+    fn weight(&self) -> i64 {
+        match self {
+            Field::Name => 4,
+            Field::Alias => 3,
+            Field::Category | Field::Tag => 2,
+            Field::Description => 1,
+        }
+    }
+
+    /// Whether matches have to run along words, as prose would otherwise match almost any letters.
+    fn words_only(&self) -> bool {
+        matches!(self, Field::Tag | Field::Description)
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Field::Name => "name",
+            Field::Category => "category",
+            Field::Alias => "alias",
+            Field::Tag => "tag",
+            Field::Description => "description",
+        }
+    }
+}
+
+/// Text outside the name and category that matched, shown next to the result.
+pub struct FieldMatch {
+    pub label: &'static str,
+    pub text: String,
+    /// Character positions in `text` that matched
+    pub positions: Vec<usize>,
+}
+
+/// A line of a script, or of a file it sources, that contains a search term.
+pub struct LineMatch {
+    /// Name of the sourced file the line is in, or None for the script itself
+    pub file: Option<String>,
+    /// 1-based line number
+    pub number: usize,
+    pub text: String,
+    /// Character positions in `text` that matched
+    pub positions: Vec<usize>,
+}
+
+/// A catalog program that matched the query.
+pub struct SearchResult {
+    pub category: String,
+    pub name: String,
+    pub score: i64,
+    /// Character positions in `name` that matched
+    pub name_matches: Vec<usize>,
+    /// Character positions in `category` that matched
+    pub category_matches: Vec<usize>,
+    pub context: Option<FieldMatch>,
+    /// Script lines that matched, when searching script contents
+    pub lines: Vec<LineMatch>,
+}
+
+/// Ranks catalog programs against the query. Every whitespace-separated term has to match one of
+/// the program's name, category, aliases, tags or description; an empty query lists everything.
+pub fn search(categories: &[Category], query: &str) -> Vec<SearchResult> {
+    let terms: Vec<Vec<char>> = query.split_whitespace().map(|term| term.to_lowercase().chars().collect()).collect();
+    let mut results: Vec<SearchResult> = categories
+        .iter()
+        .filter(|c| c.kind == CategoryKind::Catalog)
+        .flat_map(|c| c.programs.iter())
+        .filter_map(|program| match_program(program, &terms))
+        .collect();
+    results.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.category.cmp(&b.category)).then_with(|| a.name.cmp(&b.name)));
+    results
+}
+
+fn match_program(program: &Program, terms: &[Vec<char>]) -> Option<SearchResult> {
+    let mut fields = vec![(Field::Name, program.name.as_str()), (Field::Category, program.category.as_str())];
+    fields.extend(program.aliases.iter().map(|alias| (Field::Alias, alias.as_str())));
+    fields.extend(program.tags.iter().map(|tag| (Field::Tag, tag.as_str())));
+    fields.extend(program.description.as_deref().map(|description| (Field::Description, description)));
+
+    let mut positions = vec![Vec::new(); fields.len()];
+    let mut field_scores = vec![0; fields.len()];
+    let mut score = 0;
+    for term in terms {
+        // Earlier fields win ties, so a term found in the name is highlighted there
+        let mut best: Option<(usize, i64, Vec<usize>)> = None;
+        for (index, (field, text)) in fields.iter().enumerate() {
+            let found = if field.words_only() { word_match(term, text) } else { fuzzy_match(term, text) };
+            if let Some((term_score, matched)) = found {
+                let term_score = term_score * field.weight();
+                if best.as_ref().is_none_or(|(_, best_score, _)| term_score > *best_score) {
+                    best = Some((index, term_score, matched));
+                }
+            }
+        }
+        let (index, term_score, matched) = best?;
+        score += term_score;
+        field_scores[index] += term_score;
+        positions[index].extend(matched);
+    }
+    for matched in &mut positions {
+        matched.sort_unstable();
+        matched.dedup();
+    }
+
+    let context = (2..fields.len())
+        .filter(|&index| field_scores[index] > 0)
+        .max_by_key(|&index| field_scores[index])
+        .map(|index| FieldMatch { label: fields[index].0.label(), text: fields[index].1.to_string(), positions: positions[index].clone() });
+    Some(SearchResult {
+        category: program.category.clone(),
+        name: program.name.clone(),
+        score,
+        name_matches: std::mem::take(&mut positions[0]),
+        category_matches: std::mem::take(&mut positions[1]),
+        context,
+        lines: Vec::new(),
+    })
+}
+
+/// Greps each catalog script, and the files it sources, for the query. Every whitespace-separated
+/// term has to occur somewhere in them, ignoring case; lines holding the most terms come first.
+pub fn search_contents(categories: &[Category], query: &str) -> Vec<SearchResult> {
+    let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    if terms.is_empty() {
+        return Vec::new();
+    }
+    let mut results: Vec<SearchResult> = categories
+        .iter()
+        .filter(|c| c.kind == CategoryKind::Catalog)
+        .flat_map(|c| c.programs.iter())
+        .filter_map(|program| grep_program(program, &terms))
+        .collect();
+    results.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.category.cmp(&b.category)).then_with(|| a.name.cmp(&b.name)));
+    results
+}
+
+fn grep_program(program: &Program, terms: &[String]) -> Option<SearchResult> {
+    let mut found = vec![false; terms.len()];
+    // (terms on the line, match)
+    let mut hits: Vec<(usize, LineMatch)> = Vec::new();
+    for (file, text) in script_files(&program.script) {
+        for (index, line) in text.lines().enumerate() {
+            let lower = line.to_lowercase();
+            let matched: Vec<usize> = (0..terms.len()).filter(|&t| lower.contains(&terms[t])).collect();
+            if matched.is_empty() {
+                continue;
+            }
+            for &t in &matched {
+                found[t] = true;
+            }
+            let text = line.replace('\t', "    ").trim().to_string();
+            let positions = term_positions(&text, matched.iter().map(|&t| terms[t].as_str()));
+            hits.push((matched.len(), LineMatch { file: file.clone(), number: index + 1, text, positions }));
+        }
+    }
+    if !found.iter().all(|&f| f) {
+        return None;
+    }
+
+    // Stable, so equally good lines stay in file order
+    hits.sort_by_key(|(count, _)| std::cmp::Reverse(*count));
+    let score = hits.iter().take(CONTEXT_LINES).map(|(count, _)| *count as i64 * 10).sum::<i64>() + hits.len().min(10) as i64;
+    Some(SearchResult {
+        category: program.category.clone(),
+        name: program.name.clone(),
+        score,
+        name_matches: Vec::new(),
+        category_matches: Vec::new(),
+        context: None,
+        lines: hits.into_iter().take(CONTEXT_LINES).map(|(_, line)| line).collect(),
+    })
+}
+
+/// The script's text followed by every file it sources, directly or through another sourced file.
+fn script_files(script: &Path) -> Vec<(Option<String>, String)> {
+    let mut files = Vec::new();
+    let mut seen: HashSet<PathBuf> = HashSet::new();
+    let mut pending = vec![(script.to_path_buf(), None)];
+    while let Some((path, label)) = pending.pop() {
+        if !seen.insert(path.canonicalize().unwrap_or_else(|_| path.clone())) {
+            continue;
+        }
+        let Ok(raw) = fs::read(&path) else { continue };
+        let text = String::from_utf8_lossy(&raw).into_owned();
+        for source in preview::find_sources(&text, &path) {
+            if let Some(resolved) = source.resolved.filter(|p| p.is_file()) {
+                let name = resolved.file_name().map(|n| n.to_string_lossy().into_owned());
+                pending.push((resolved, name));
+            }
+        }
+        files.push((label, text));
+    }
+    files
+}
+
+/// Character positions of every occurrence of the terms in the text, ignoring case.
+fn term_positions<'a>(text: &str, terms: impl Iterator<Item = &'a str>) -> Vec<usize> {
+    let lower: Vec<char> = text.chars().map(|c| c.to_lowercase().next().unwrap_or(c)).collect();
+    let mut positions = Vec::new();
+    for term in terms {
+        let term: Vec<char> = term.chars().collect();
+        if term.is_empty() || term.len() > lower.len() {
+            continue;
+        }
+        for start in 0..=lower.len() - term.len() {
+            if lower[start..start + term.len()] == term[..] {
+                positions.extend(start..start + term.len());
+            }
+        }
+    }
+    positions.sort_unstable();
+    positions.dedup();
+    positions
+}
+
+/// Matches the lowercase pattern as a subsequence of the text, ignoring case. Returns a score that
+/// favours consecutive characters, word starts and early matches, plus the matched positions.
+pub fn fuzzy_match(pattern: &[char], text: &str) -> Option<(i64, Vec<usize>)> {
+    subsequence_match(pattern, text, false)
+}
+
+/// Like `fuzzy_match`, but every matched character has to follow the previous one or start a
+/// word, so "gal" finds "Guided Arch Linux" and "arch linux" but not scattered letters.
+fn word_match(pattern: &[char], text: &str) -> Option<(i64, Vec<usize>)> {
+    subsequence_match(pattern, text, true)
+}
+
+fn subsequence_match(pattern: &[char], text: &str, words_only: bool) -> Option<(i64, Vec<usize>)> {
+    if pattern.is_empty() {
+        return Some((0, Vec::new()));
+    }
+    let chars: Vec<char> = text.chars().collect();
+    let lower: Vec<char> = chars.iter().map(|c| c.to_lowercase().next().unwrap_or(*c)).collect();
+
+    let mut best: Option<(i64, Vec<usize>)> = None;
+    let allowed = |pos: usize, previous: usize| !words_only || pos == previous + 1 || word_start(&chars, pos);
+    for start in (0..lower.len()).filter(|&i| lower[i] == pattern[0] && (!words_only || word_start(&chars, i))) {
+        let mut matched = vec![start];
+        let mut next = start + 1;
+        for &c in &pattern[1..] {
+            let previous = next - 1;
+            let Some(offset) = lower[next..].iter().enumerate().position(|(i, &l)| l == c && allowed(next + i, previous)) else { break };
+            matched.push(next + offset);
+            next += offset + 1;
+        }
+        if matched.len() < pattern.len() {
+            // Without the word rule a later start cannot find what this one did not
+            if words_only {
+                continue;
+            }
+            break;
+        }
+        let score = score_match(&chars, &matched);
+        if best.as_ref().is_none_or(|(best_score, _)| score > *best_score) {
+            best = Some((score, matched));
+        }
+    }
+    best
+}
+
+fn score_match(chars: &[char], matched: &[usize]) -> i64 {
+    let mut score = 0;
+    for (i, &pos) in matched.iter().enumerate() {
+        score += 16;
+        if word_start(chars, pos) {
+            score += 12;
+        }
+        if i > 0 {
+            match pos - matched[i - 1] - 1 {
+                0 => score += 16,
+                gap => score -= gap.min(8) as i64,
+            }
+        }
+    }
+    if matched.len() == chars.len() {
+        score += 20;
+    }
+    score - matched[0].min(10) as i64
+}
+
+/// Whether the character begins a word, including camelCase humps.
+fn word_start(chars: &[char], pos: usize) -> bool {
+    pos == 0 || !chars[pos - 1].is_alphanumeric() || (chars[pos - 1].is_lowercase() && chars[pos].is_uppercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{CatalogLayer, CatalogSource};
+    use crate::ProgramEntry;
+
+    fn score(pattern: &str, text: &str) -> Option<i64> {
+        let pattern: Vec<char> = pattern.chars().collect();
+        fuzzy_match(&pattern, text).map(|(score, _)| score)
+    }
+
+    #[test]
+    fn fuzzy_match_finds_subsequences_ignoring_case() {
+        let pattern: Vec<char> = "vc".chars().collect();
+        assert_eq!(fuzzy_match(&pattern, "VS Code").map(|(_, positions)| positions), Some(vec![0, 3]));
+        assert_eq!(fuzzy_match(&[], "Git"), Some((0, Vec::new())));
+        assert_eq!(score("xyz", "Git"), None);
+        assert_eq!(score("tig", "Git"), None);
+    }
+
+    #[test]
+    fn fuzzy_match_picks_the_best_start() {
+        let pattern: Vec<char> = "code".chars().collect();
+        assert_eq!(fuzzy_match(&pattern, "xcode Code").map(|(_, positions)| positions), Some(vec![6, 7, 8, 9]));
+    }
+
+    #[test]
+    fn fuzzy_score_ranks_closer_matches_higher() {
+        // A whole-name match beats a prefix, which beats scattered letters
+        assert!(score("git", "Git") > score("git", "Gitea"));
+        assert!(score("git", "Gitea") > score("git", "Good Ideas Too"));
+        // Word starts count, including camelCase humps
+        assert!(score("vc", "VisualCode") > score("vc", "Vaccine"));
+        // So does matching early
+        assert!(score("go", "Go tools") > score("go", "Install Go"));
+    }
+
+    #[test]
+    fn word_match_follows_words() {
+        let pattern: Vec<char> = "gal".chars().collect();
+        let description = "Guided Arch Linux install";
+        assert_eq!(word_match(&pattern, description).map(|(_, positions)| positions), Some(vec![0, 7, 12]));
+        let pattern: Vec<char> = "archl".chars().collect();
+        assert!(word_match(&pattern, description).is_some());
+        // Letters from the middle of words are not enough
+        let pattern: Vec<char> = "uid".chars().collect();
+        assert!(word_match(&pattern, description).is_none());
+        assert!(fuzzy_match(&pattern, description).is_some());
+    }
+
+    #[test]
+    fn descriptions_need_more_than_scattered_letters() {
+        let spec = toml::from_str(
+            r#"
+            script = "scripts/archinstall.sh"
+            description = "Guided Arch Linux install, partitions and formats disks"
+            tags = ["install", "disk"]
+            "#,
+        )
+        .unwrap();
+        let layer = CatalogLayer { source: CatalogSource::Bundled, path: PathBuf::from("/opt/toolbox/config.toml") };
+        let program = Program::from_entry("Arch-Install-scripts", "ArchInstall", ProgramEntry::Detailed(spec), &layer).unwrap();
+        let query = |query: &str| -> Vec<Vec<char>> { query.split_whitespace().map(|term| term.chars().collect()).collect() };
+
+        assert!(match_program(&program, &query("lfs")).is_none());
+        let result = match_program(&program, &query("partition")).unwrap();
+        assert_eq!(result.context.map(|context| context.label), Some("description"));
+        let result = match_program(&program, &query("disk")).unwrap();
+        assert_eq!(result.context.map(|context| context.label), Some("tag"));
+        // Names still match loosely
+        assert!(match_program(&program, &query("ain")).is_some());
+    }
+}
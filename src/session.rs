@@ -0,0 +1,473 @@
+use std::cell::Cell;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::os::unix::process::ExitStatusExt;
+use std::path::{Path, PathBuf};
+use std::process::ExitStatus;
+use std::sync::{mpsc, Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, Local};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use portable_pty::{native_pty_system, Child, CommandBuilder, MasterPty, PtySize};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+
+use crate::params::Invocation;
+use crate::{state, Program};
+
+/// Lines of output kept above the visible screen for scrolling back
+const SCROLLBACK_LINES: usize = 5000;
+/// How long an attached run waits for typed input before checking on the script again
+const INPUT_POLL_MS: u64 = 50;
+/// What a terminal reads as end of file at the start of a line
+const EOF_CHAR: u8 = 0x04;
+/// How long an attached run keeps copying output after the script exits
+const OUTPUT_DRAIN_MS: u64 = 500;
+
+/// How a finished script ended.
+pub struct SessionExit {
+    pub exit_code: Option<i32>,
+    pub success: bool,
+}
+
+/// A script running under a pseudo-terminal whose screen is rendered inside the TUI.
+pub struct ScriptSession {
+    pub program: Program,
+    pub started_at: DateTime<Local>,
+    pub finished_at: Option<DateTime<Local>>,
+    pub exit: Option<SessionExit>,
+    /// File the raw terminal output is copied to
+    pub output: Option<PathBuf>,
+    parser: Arc<Mutex<vt100::Parser>>,
+    master: Box<dyn MasterPty + Send>,
+    writer: Box<dyn Write + Send>,
+    child: Box<dyn Child + Send + Sync>,
+    size: Cell<(u16, u16)>,
+    scroll: usize,
+}
+
+impl ScriptSession {
+    pub fn spawn(program: &Program, invocation: &Invocation, rows: u16, cols: u16) -> std::io::Result<Self> {
+        let started_at = Local::now();
+        let rows = rows.max(1);
+        let cols = cols.max(1);
+        let pair = native_pty_system()
+            .openpty(PtySize { rows, cols, pixel_width: 0, pixel_height: 0 })
+            .map_err(std::io::Error::other)?;
+
+        let mut command = CommandBuilder::new("bash");
+        command.args(invocation.bash_args(&program.script));
+        command.env("TERM", "xterm-256color");
+        for (name, value) in &invocation.env {
+            command.env(name, value);
+        }
+        if let Ok(cwd) = std::env::current_dir() {
+            command.cwd(cwd);
+        }
+
+        let mut writer = pair.master.take_writer().map_err(std::io::Error::other)?;
+        type_input(pair.master.as_ref(), &mut writer, invocation)?;
+
+        let child = pair.slave.spawn_command(command).map_err(std::io::Error::other)?;
+        // Only the child should hold the slave end, otherwise the reader never sees EOF
+        drop(pair.slave);
+
+        let mut reader = pair.master.try_clone_reader().map_err(std::io::Error::other)?;
+        let parser = Arc::new(Mutex::new(vt100::Parser::new(rows, cols, SCROLLBACK_LINES)));
+
+        // Losing the copy on disk should not stop the script from running
+        let output = state::new_output_path(&program.key(), started_at).ok();
+        let mut output_file = output.as_ref().and_then(|path| File::create(path).ok());
+
+        let screen = Arc::clone(&parser);
+        std::thread::spawn(move || {
+            let mut buf = [0u8; 8192];
+            while let Ok(n) = reader.read(&mut buf) {
+                if n == 0 {
+                    break;
+                }
+                if let Ok(mut parser) = screen.lock() {
+                    parser.process(&buf[..n]);
+                }
+                if let Some(file) = output_file.as_mut() {
+                    let _ = file.write_all(&buf[..n]);
+                }
+            }
+        });
+
+        Ok(ScriptSession {
+            program: program.clone(),
+            started_at,
+            finished_at: None,
+            exit: None,
+            output,
+            parser,
+            master: pair.master,
+            writer,
+            child,
+            size: Cell::new((rows, cols)),
+            scroll: 0,
+        })
+    }
+
+    /// Checks whether the script has exited. Returns true only on the call that first notices it.
+    pub fn poll_exit(&mut self) -> bool {
+        if self.exit.is_some() {
+            return false;
+        }
+        match self.child.try_wait() {
+            Ok(Some(status)) => {
+                self.exit = Some(SessionExit {
+                    exit_code: Some(status.exit_code() as i32),
+                    success: status.success(),
+                });
+            }
+            Ok(None) => return false,
+            Err(_) => self.exit = Some(SessionExit { exit_code: None, success: false }),
+        }
+        self.finished_at = Some(Local::now());
+        true
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.exit.is_none()
+    }
+
+    pub fn elapsed(&self) -> chrono::Duration {
+        self.finished_at.unwrap_or_else(Local::now) - self.started_at
+    }
+
+    /// Keeps the pseudo-terminal the same size as the pane it is drawn in.
+    pub fn resize(&self, rows: u16, cols: u16) {
+        let (rows, cols) = (rows.max(1), cols.max(1));
+        if self.size.get() == (rows, cols) {
+            return;
+        }
+        self.size.set((rows, cols));
+        if let Ok(mut parser) = self.parser.lock() {
+            parser.set_size(rows, cols);
+        }
+        let _ = self.master.resize(PtySize { rows, cols, pixel_width: 0, pixel_height: 0 });
+    }
+
+    pub fn scroll_up(&mut self, lines: usize) {
+        self.set_scroll(self.scroll + lines);
+    }
+
+    pub fn scroll_down(&mut self, lines: usize) {
+        self.set_scroll(self.scroll.saturating_sub(lines));
+    }
+
+    fn set_scroll(&mut self, lines: usize) {
+        if let Ok(mut parser) = self.parser.lock() {
+            parser.set_scrollback(lines);
+            // The parser clamps to what it actually has, so read it back
+            self.scroll = parser.screen().scrollback();
+        }
+    }
+
+    pub fn is_scrolled(&self) -> bool {
+        self.scroll > 0
+    }
+
+    /// Handles a key while the session is on screen. Returns true when the user closes a finished session.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let page = self.size.get().0 as usize / 2;
+        match key.code {
+            KeyCode::PageUp if key.modifiers.contains(KeyModifiers::SHIFT) || !self.is_running() => {
+                self.scroll_up(page);
+                return false;
+            }
+            KeyCode::PageDown if key.modifiers.contains(KeyModifiers::SHIFT) || !self.is_running() => {
+                self.scroll_down(page);
+                return false;
+            }
+            _ => {}
+        }
+
+        if !self.is_running() {
+            return match key.code {
+                KeyCode::Up => {
+                    self.scroll_up(1);
+                    false
+                }
+                KeyCode::Down => {
+                    self.scroll_down(1);
+                    false
+                }
+                KeyCode::Enter | KeyCode::Esc | KeyCode::Char('q') => true,
+                _ => false,
+            };
+        }
+
+        let application_cursor = self.parser.lock().map(|p| p.screen().application_cursor()).unwrap_or(false);
+        if let Some(bytes) = key_to_bytes(key, application_cursor) {
+            self.set_scroll(0);
+            let _ = self.writer.write_all(&bytes);
+            let _ = self.writer.flush();
+        }
+        false
+    }
+
+    /// Renders the visible screen, plus the cursor position when it should be shown.
+    pub fn render(&self) -> (Vec<Line<'static>>, Option<(u16, u16)>) {
+        let Ok(parser) = self.parser.lock() else {
+            return (Vec::new(), None);
+        };
+        let screen = parser.screen();
+        let (rows, cols) = screen.size();
+
+        let mut lines = Vec::with_capacity(rows as usize);
+        for row in 0..rows {
+            let mut spans: Vec<Span<'static>> = Vec::new();
+            let mut text = String::new();
+            let mut style = Style::default();
+            for col in 0..cols {
+                let Some(cell) = screen.cell(row, col) else { continue };
+                if cell.is_wide_continuation() {
+                    continue;
+                }
+                let cell_style = cell_style(cell);
+                if cell_style != style && !text.is_empty() {
+                    spans.push(Span::styled(std::mem::take(&mut text), style));
+                }
+                style = cell_style;
+                if cell.has_contents() {
+                    text.push_str(&cell.contents());
+                } else {
+                    text.push(' ');
+                }
+            }
+            if !text.is_empty() {
+                spans.push(Span::styled(text, style));
+            }
+            lines.push(Line::from(spans));
+        }
+
+        let cursor = (self.is_running() && self.scroll == 0 && !screen.hide_cursor()).then(|| {
+            let (row, col) = screen.cursor_position();
+            (col, row)
+        });
+        (lines, cursor)
+    }
+}
+
+impl Drop for ScriptSession {
+    fn drop(&mut self) {
+        if self.is_running() {
+            let _ = self.child.kill();
+        }
+    }
+}
+
+fn convert_color(color: vt100::Color) -> Color {
+    match color {
+        vt100::Color::Default => Color::Reset,
+        vt100::Color::Idx(i) => Color::Indexed(i),
+        vt100::Color::Rgb(r, g, b) => Color::Rgb(r, g, b),
+    }
+}
+
+fn cell_style(cell: &vt100::Cell) -> Style {
+    let mut style = Style::default().fg(convert_color(cell.fgcolor())).bg(convert_color(cell.bgcolor()));
+    if cell.bold() {
+        style = style.add_modifier(Modifier::BOLD);
+    }
+    if cell.italic() {
+        style = style.add_modifier(Modifier::ITALIC);
+    }
+    if cell.underline() {
+        style = style.add_modifier(Modifier::UNDERLINED);
+    }
+    if cell.inverse() {
+        style = style.add_modifier(Modifier::REVERSED);
+    }
+    style
+}
+
+/// Translates a key press into the bytes a terminal would send for it.
+fn key_to_bytes(key: KeyEvent, application_cursor: bool) -> Option<Vec<u8>> {
+    let cursor = |c: char| {
+        if application_cursor { format!("\x1bO{}", c) } else { format!("\x1b[{}", c) }.into_bytes()
+    };
+    let bytes = match key.code {
+        KeyCode::Char(c) if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            let c = c.to_ascii_lowercase();
+            if !c.is_ascii_lowercase() {
+                return None;
+            }
+            vec![c as u8 - b'a' + 1]
+        }
+        KeyCode::Char(c) => {
+            let mut bytes = Vec::new();
+            if key.modifiers.contains(KeyModifiers::ALT) {
+                bytes.push(0x1b);
+            }
+            bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+            bytes
+        }
+        KeyCode::Enter => vec![b'\r'],
+        KeyCode::Backspace => vec![0x7f],
+        KeyCode::Tab => vec![b'\t'],
+        KeyCode::BackTab => b"\x1b[Z".to_vec(),
+        KeyCode::Esc => vec![0x1b],
+        KeyCode::Up => cursor('A'),
+        KeyCode::Down => cursor('B'),
+        KeyCode::Right => cursor('C'),
+        KeyCode::Left => cursor('D'),
+        KeyCode::Home => cursor('H'),
+        KeyCode::End => cursor('F'),
+        KeyCode::PageUp => b"\x1b[5~".to_vec(),
+        KeyCode::PageDown => b"\x1b[6~".to_vec(),
+        KeyCode::Insert => b"\x1b[2~".to_vec(),
+        KeyCode::Delete => b"\x1b[3~".to_vec(),
+        _ => return None,
+    };
+    Some(bytes)
+}
+
+/// Runs a script in a pseudo-terminal of its own that stands in for the toolbox's terminal, for the
+/// command line. What the user types is passed through and what the script prints is shown as it
+/// comes and copied to `output`, so the script sees a terminal while its output is still saved.
+pub fn run_attached(script: &Path, invocation: &Invocation, output: Option<&Path>) -> std::io::Result<ExitStatus> {
+    let (cols, rows) = crossterm::terminal::size().unwrap_or((80, 24));
+    let pair = native_pty_system()
+        .openpty(PtySize { rows, cols, pixel_width: 0, pixel_height: 0 })
+        .map_err(std::io::Error::other)?;
+
+    let mut command = CommandBuilder::new("bash");
+    command.args(invocation.bash_args(script));
+    for (name, value) in &invocation.env {
+        command.env(name, value);
+    }
+    if let Ok(cwd) = std::env::current_dir() {
+        command.cwd(cwd);
+    }
+
+    let mut writer = pair.master.take_writer().map_err(std::io::Error::other)?;
+    type_input(pair.master.as_ref(), &mut writer, invocation)?;
+    let mut child = pair.slave.spawn_command(command).map_err(std::io::Error::other)?;
+    drop(pair.slave);
+
+    let mut reader = pair.master.try_clone_reader().map_err(std::io::Error::other)?;
+    let mut output_file = output.and_then(|path| File::create(path).ok());
+    let (finished, copied) = mpsc::channel::<()>();
+    std::thread::spawn(move || {
+        let mut stdout = std::io::stdout();
+        let mut buf = [0u8; 8192];
+        while let Ok(n) = reader.read(&mut buf) {
+            if n == 0 {
+                break;
+            }
+            let _ = stdout.write_all(&buf[..n]);
+            let _ = stdout.flush();
+            if let Some(file) = output_file.as_mut() {
+                let _ = file.write_all(&buf[..n]);
+            }
+        }
+        let _ = finished.send(());
+    });
+
+    // Keys go to the script as they are typed; its own terminal echoes them and handles Ctrl+C
+    let _raw = RawMode::enable();
+    let mut forwarding = true;
+    let mut size = (rows, cols);
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if !forwarding {
+            std::thread::sleep(Duration::from_millis(INPUT_POLL_MS));
+        } else if let Some(input) = read_stdin(INPUT_POLL_MS) {
+            match input {
+                Ok(bytes) if !bytes.is_empty() => {
+                    let _ = writer.write_all(&bytes);
+                    let _ = writer.flush();
+                }
+                // End of input, or an unreadable stdin, reaches the script as a Ctrl+D would
+                _ => {
+                    forwarding = false;
+                    let _ = writer.write_all(&[EOF_CHAR]);
+                    let _ = writer.flush();
+                }
+            }
+        }
+        if let Ok((cols, rows)) = crossterm::terminal::size() {
+            if (rows, cols) != size {
+                size = (rows, cols);
+                let _ = pair.master.resize(PtySize { rows, cols, pixel_width: 0, pixel_height: 0 });
+            }
+        }
+    };
+    // Background jobs can keep the terminal open after the script is gone
+    let _ = copied.recv_timeout(Duration::from_millis(OUTPUT_DRAIN_MS));
+
+    let code = if status.success() { 0 } else { status.exit_code().clamp(1, 255) as i32 };
+    Ok(ExitStatus::from_raw(code << 8))
+}
+
+/// Waits up to `timeout_ms` for input on stdin and reads what is there. None when nothing came.
+fn read_stdin(timeout_ms: u64) -> Option<std::io::Result<Vec<u8>>> {
+    let mut poll = libc::pollfd { fd: libc::STDIN_FILENO, events: libc::POLLIN, revents: 0 };
+    // SAFETY: poll gets one valid pollfd and read a buffer of the length it is told
+    unsafe {
+        if libc::poll(&mut poll, 1, timeout_ms as libc::c_int) <= 0 {
+            return None;
+        }
+        let mut buf = [0u8; 1024];
+        let n = libc::read(libc::STDIN_FILENO, buf.as_mut_ptr().cast(), buf.len());
+        if n < 0 {
+            return Some(Err(std::io::Error::last_os_error()));
+        }
+        Some(Ok(buf[..n as usize].to_vec()))
+    }
+}
+
+/// Raw mode on the toolbox's terminal while a script runs attached to it, when stdin is one.
+struct RawMode(bool);
+
+impl RawMode {
+    fn enable() -> Self {
+        // SAFETY: isatty only inspects the descriptor
+        let is_tty = unsafe { libc::isatty(libc::STDIN_FILENO) } == 1;
+        RawMode(is_tty && crossterm::terminal::enable_raw_mode().is_ok())
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        if self.0 {
+            let _ = crossterm::terminal::disable_raw_mode();
+        }
+    }
+}
+
+/// Types the invocation's hidden input line into the terminal before the script starts. Echo is
+/// off so the line shows neither on screen nor in the saved output; the prelude turns it back on
+/// once it has read the line.
+fn type_input(master: &dyn MasterPty, writer: &mut impl Write, invocation: &Invocation) -> std::io::Result<()> {
+    let Some(input) = &invocation.input else { return Ok(()) };
+    disable_echo(master)?;
+    writer.write_all(input.as_str().as_bytes())?;
+    writer.write_all(b"\n")?;
+    writer.flush()
+}
+
+/// Stops the terminal from echoing what is typed into it.
+fn disable_echo(master: &dyn MasterPty) -> std::io::Result<()> {
+    let fd = master.as_raw_fd().ok_or_else(|| std::io::Error::other("the terminal has no file descriptor"))?;
+    // SAFETY: fd is the open pty master and termios is only read after tcgetattr filled it in
+    unsafe {
+        let mut termios = std::mem::MaybeUninit::<libc::termios>::uninit();
+        if libc::tcgetattr(fd, termios.as_mut_ptr()) != 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        let mut termios = termios.assume_init();
+        termios.c_lflag &= !libc::ECHO;
+        if libc::tcsetattr(fd, libc::TCSANOW, &termios) != 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
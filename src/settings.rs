@@ -0,0 +1,102 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{config, logging, GITHUB_REPO};
+
+const SETTINGS_FILE: &str = "settings.toml";
+
+/// User preferences from `$XDG_CONFIG_HOME/linuxtoolbox/settings.toml`. Every key is optional.
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Settings {
+    pub update: UpdateSettings,
+    pub queue: QueueSettings,
+    pub sudo: SudoSettings,
+}
+
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UpdateSettings {
+    /// Look for a newer release when the menu starts
+    pub check: bool,
+    /// GitHub repository to take releases from, as "owner/name"
+    pub repo: String,
+    /// Base URL of the GitHub API, or of anything serving the same release JSON
+    pub api_url: String,
+    /// How long a check result is reused before asking again
+    pub cache_hours: u64,
+}
+
+impl Default for UpdateSettings {
+    fn default() -> Self {
+        UpdateSettings {
+            check: true,
+            repo: GITHUB_REPO.to_string(),
+            api_url: "https://api.github.com".to_string(),
+            cache_hours: 24,
+        }
+    }
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct QueueSettings {
+    /// What a queued run does when one of its programs fails
+    pub on_failure: OnFailure,
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OnFailure {
+    /// Stop and ask whether to go on with the rest
+    #[default]
+    Pause,
+    Continue,
+}
+
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SudoSettings {
+    /// Ask for the sudo password once, before the first program that needs root
+    pub preauth: bool,
+    /// What happens when the toolbox itself is started as root
+    pub as_root: RootPolicy,
+}
+
+impl Default for SudoSettings {
+    fn default() -> Self {
+        SudoSettings { preauth: true, as_root: RootPolicy::default() }
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RootPolicy {
+    Allow,
+    #[default]
+    Warn,
+    Refuse,
+}
+
+pub fn settings_path() -> PathBuf {
+    config::user_config_dir().join(SETTINGS_FILE)
+}
+
+/// Loads the settings file. A missing file means defaults; a broken one is logged and ignored.
+pub fn load() -> Settings {
+    let path = settings_path();
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Settings::default(),
+        Err(e) => {
+            logging::warn("Failed to read settings", json!({ "path": path, "error": e.to_string() }));
+            return Settings::default();
+        }
+    };
+    toml::from_str(&contents).unwrap_or_else(|e| {
+        logging::warn("Invalid settings file, using defaults", json!({ "path": path, "error": e.to_string() }));
+        Settings::default()
+    })
+}
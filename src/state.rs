@@ -0,0 +1,204 @@
+use std::collections::HashSet;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+const FAVORITES_FILE: &str = "favorites.json";
+const HISTORY_FILE: &str = "history.jsonl";
+/// Directory under the state dir holding one output file per run
+const RUNS_DIR: &str = "runs";
+/// How many runs are kept, with their output files, and loaded back for the history screen
+const HISTORY_LIMIT: usize = 200;
+
+/// Per-user state directory: `$XDG_STATE_HOME/linuxtoolbox`, falling back to `~/.local/state/linuxtoolbox`.
+pub fn state_dir() -> PathBuf {
+    let base = std::env::var_os("XDG_STATE_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/state")))
+        .unwrap_or_else(|| PathBuf::from("."));
+    base.join("linuxtoolbox")
+}
+
+/// Favorites are stored as `Category/Program` keys so they survive reordering of the catalog.
+pub fn load_favorites() -> HashSet<String> {
+    fs::read_to_string(state_dir().join(FAVORITES_FILE))
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_favorites(favorites: &[String]) -> std::io::Result<()> {
+    let dir = state_dir();
+    fs::create_dir_all(&dir)?;
+    let json = serde_json::to_string_pretty(favorites)?;
+    fs::write(dir.join(FAVORITES_FILE), json)
+}
+
+/// One execution of a catalog program, appended to `history.jsonl` in the state directory.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct RunRecord {
+    pub key: String,
+    pub category: String,
+    pub program: String,
+    pub script: PathBuf,
+    pub started_at: DateTime<Local>,
+    pub finished_at: DateTime<Local>,
+    pub duration_ms: i64,
+    pub exit_code: Option<i32>,
+    pub success: bool,
+    pub error: Option<String>,
+    /// Combined stdout/stderr of the run, absent for runs that never started
+    #[serde(default)]
+    pub output: Option<PathBuf>,
+}
+
+impl RunRecord {
+    pub fn duration(&self) -> chrono::Duration {
+        chrono::Duration::milliseconds(self.duration_ms)
+    }
+
+    pub fn status_text(&self) -> String {
+        match (&self.error, self.exit_code) {
+            (Some(error), _) => error.clone(),
+            (None, Some(code)) => format!("exit {}", code),
+            (None, None) => "killed by signal".to_string(),
+        }
+    }
+}
+
+/// Picks a fresh timestamped file for a run's output, e.g. `runs/20241004-171731-Development-Git.log`.
+pub fn new_output_path(key: &str, started_at: DateTime<Local>) -> std::io::Result<PathBuf> {
+    let dir = state_dir().join(RUNS_DIR);
+    fs::create_dir_all(&dir)?;
+    let name: String = key
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '-' })
+        .collect();
+    Ok(dir.join(format!("{}-{}.log", started_at.format("%Y%m%d-%H%M%S%.3f"), name)))
+}
+
+pub fn append_history(record: &RunRecord) -> std::io::Result<()> {
+    let dir = state_dir();
+    fs::create_dir_all(&dir)?;
+    let mut file = OpenOptions::new().create(true).append(true).open(dir.join(HISTORY_FILE))?;
+    writeln!(file, "{}", serde_json::to_string(record)?)?;
+    drop(file);
+    prune_history(&dir)
+}
+
+/// Keeps the newest `HISTORY_LIMIT` runs in the history file and deletes the output files of the rest.
+fn prune_history(dir: &Path) -> std::io::Result<()> {
+    let path = dir.join(HISTORY_FILE);
+    let contents = fs::read_to_string(&path)?;
+    let lines: Vec<&str> = contents.lines().collect();
+    if lines.len() <= HISTORY_LIMIT {
+        return Ok(());
+    }
+    let (dropped, kept) = lines.split_at(lines.len() - HISTORY_LIMIT);
+    let runs = dir.join(RUNS_DIR);
+    for line in dropped {
+        let output = serde_json::from_str::<RunRecord>(line).ok().and_then(|record| record.output);
+        // Only files this program created, whatever an edited history file may point at
+        if let Some(output) = output.filter(|output| output.starts_with(&runs)) {
+            let _ = fs::remove_file(output);
+        }
+    }
+    // Renamed into place so an interrupted write never loses the history
+    let temp = dir.join(format!("{}.tmp", HISTORY_FILE));
+    fs::write(&temp, format!("{}\n", kept.join("\n")))?;
+    fs::rename(temp, path)
+}
+
+/// Returns the run history, newest first. Lines that fail to parse are skipped.
+pub fn load_history() -> Vec<RunRecord> {
+    let contents = fs::read_to_string(state_dir().join(HISTORY_FILE)).unwrap_or_default();
+    let mut records: Vec<RunRecord> = contents
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+    records.reverse();
+    records.truncate(HISTORY_LIMIT);
+    records
+}
+
+pub fn format_duration(duration: chrono::Duration) -> String {
+    let secs = duration.num_seconds();
+    if secs >= 3600 {
+        format!("{}h{:02}m", secs / 3600, secs % 3600 / 60)
+    } else if secs >= 60 {
+        format!("{}m{:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{:.1}s", duration.num_milliseconds() as f64 / 1000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("linuxtoolbox-state-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join(RUNS_DIR)).unwrap();
+        dir
+    }
+
+    fn record(index: usize, output: Option<PathBuf>) -> String {
+        let now = Local::now();
+        let record = RunRecord {
+            key: format!("Test/P{}", index),
+            category: "Test".to_string(),
+            program: format!("P{}", index),
+            script: PathBuf::from("/opt/toolbox/scripts/p.sh"),
+            started_at: now,
+            finished_at: now,
+            duration_ms: 0,
+            exit_code: Some(0),
+            success: true,
+            error: None,
+            output,
+        };
+        serde_json::to_string(&record).unwrap()
+    }
+
+    #[test]
+    fn prune_history_keeps_the_newest_runs_and_their_outputs() {
+        let dir = scratch_dir("prune");
+        let outside = dir.join("notes.txt");
+        fs::write(&outside, "not a run").unwrap();
+        let mut lines = Vec::new();
+        for index in 0..HISTORY_LIMIT + 2 {
+            let output = dir.join(RUNS_DIR).join(format!("{}.log", index));
+            fs::write(&output, "output").unwrap();
+            lines.push(record(index, Some(output)));
+        }
+        // An edited history pointing outside runs/ must not get that file deleted
+        lines[0] = record(0, Some(outside.clone()));
+        fs::write(dir.join(HISTORY_FILE), format!("{}\n", lines.join("\n"))).unwrap();
+
+        prune_history(&dir).unwrap();
+
+        let kept = fs::read_to_string(dir.join(HISTORY_FILE)).unwrap();
+        assert_eq!(kept.lines().count(), HISTORY_LIMIT);
+        assert_eq!(kept.lines().next(), Some(lines[2].as_str()));
+        assert!(outside.exists());
+        assert!(!dir.join(RUNS_DIR).join("1.log").exists());
+        assert!(dir.join(RUNS_DIR).join("2.log").exists());
+        assert!(!dir.join(format!("{}.tmp", HISTORY_FILE)).exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn prune_history_leaves_short_histories_alone() {
+        let dir = scratch_dir("short");
+        let history = format!("{}\n{}\n", record(0, None), record(1, None));
+        fs::write(dir.join(HISTORY_FILE), &history).unwrap();
+        prune_history(&dir).unwrap();
+        assert_eq!(fs::read_to_string(dir.join(HISTORY_FILE)).unwrap(), history);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
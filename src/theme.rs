@@ -0,0 +1,251 @@
+use std::fs;
+use std::path::Path;
+
+use include_dir::{include_dir, Dir};
+use ratatui::style::{Color, Modifier, Style};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{config, logging};
+
+/// Themes shipped with the binary. The number prefix on each file sets the Tab order.
+static BUILTIN_THEMES: Dir<'static> = include_dir!("$CARGO_MANIFEST_DIR/themes");
+/// Remembers the chosen theme, in the user config directory
+const SELECTED_THEME_FILE: &str = "color_scheme.json";
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThemeFile {
+    name: String,
+    background: String,
+    foreground: String,
+    /// Fallback for selection, accent, title and status_bar
+    highlight: String,
+    border: Option<String>,
+    selection: Option<String>,
+    accent: Option<String>,
+    title: Option<String>,
+    warning: Option<String>,
+    error: Option<String>,
+    success: Option<String>,
+    muted: Option<String>,
+    status_bar: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct Theme {
+    pub name: String,
+    /// File name without the order prefix, e.g. "tokyo" for 27-tokyo.toml
+    pub id: String,
+    pub background: Color,
+    pub foreground: Color,
+    /// Widget borders
+    pub border: Color,
+    /// Background of the selected list row; its text uses `background`
+    pub selection: Color,
+    /// Labels, list bullets, key names and other emphasised text
+    pub accent: Color,
+    /// The application name in the title bar
+    pub title: Color,
+    pub warning: Color,
+    pub error: Color,
+    pub success: Color,
+    /// Line numbers, comments and other secondary text
+    pub muted: Color,
+    /// Status messages shown over the key hints
+    pub status_bar: Color,
+}
+
+impl Theme {
+    fn from_toml(file_name: &str, contents: &str) -> Result<Self, String> {
+        let file: ThemeFile = toml::from_str(contents).map_err(|e| e.to_string())?;
+        let foreground = parse_color(&file.foreground)?;
+        let highlight = parse_color(&file.highlight)?;
+        let role = |value: &Option<String>, default: Color| value.as_deref().map_or(Ok(default), parse_color);
+        let stem = file_name.trim_end_matches(".toml");
+        let id = stem.split_once('-').filter(|(prefix, _)| prefix.chars().all(|c| c.is_ascii_digit())).map_or(stem, |(_, id)| id);
+        Ok(Theme {
+            name: file.name,
+            id: id.to_lowercase(),
+            background: parse_color(&file.background)?,
+            foreground,
+            border: role(&file.border, foreground)?,
+            selection: role(&file.selection, highlight)?,
+            accent: role(&file.accent, highlight)?,
+            title: role(&file.title, highlight)?,
+            warning: role(&file.warning, Color::Yellow)?,
+            error: role(&file.error, Color::Red)?,
+            success: role(&file.success, Color::Green)?,
+            muted: role(&file.muted, Color::DarkGray)?,
+            status_bar: role(&file.status_bar, highlight)?,
+        })
+    }
+
+    /// Plain text on the theme background.
+    pub fn text(&self) -> Style {
+        Style::default().fg(self.foreground).bg(self.background)
+    }
+
+    pub fn border_style(&self) -> Style {
+        Style::default().fg(self.border).bg(self.background)
+    }
+
+    /// The highlighted row of a list.
+    pub fn selected(&self) -> Style {
+        Style::default().bg(self.selection).fg(self.background).add_modifier(Modifier::BOLD)
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name) || self.id.eq_ignore_ascii_case(name)
+    }
+}
+
+/// Accepts "reset", terminal color names like "light-blue", "#rrggbb", or a 0-255 palette index.
+pub fn parse_color(value: &str) -> Result<Color, String> {
+    let value = value.trim();
+    if let Some(hex) = value.strip_prefix('#') {
+        let channel = |range: std::ops::Range<usize>| hex.get(range).and_then(|h| u8::from_str_radix(h, 16).ok());
+        return match (hex.len(), channel(0..2), channel(2..4), channel(4..6)) {
+            (6, Some(r), Some(g), Some(b)) => Ok(Color::Rgb(r, g, b)),
+            _ => Err(format!("'{}' is not a #rrggbb color", value)),
+        };
+    }
+    if let Ok(index) = value.parse::<u8>() {
+        return Ok(Color::Indexed(index));
+    }
+    let color = match value.to_ascii_lowercase().replace(['_', ' '], "-").as_str() {
+        "reset" | "default" => Color::Reset,
+        "black" => Color::Black,
+        "red" => Color::Red,
+        "green" => Color::Green,
+        "yellow" => Color::Yellow,
+        "blue" => Color::Blue,
+        "magenta" => Color::Magenta,
+        "cyan" => Color::Cyan,
+        "gray" | "grey" => Color::Gray,
+        "dark-gray" | "dark-grey" => Color::DarkGray,
+        "light-red" => Color::LightRed,
+        "light-green" => Color::LightGreen,
+        "light-yellow" => Color::LightYellow,
+        "light-blue" => Color::LightBlue,
+        "light-magenta" => Color::LightMagenta,
+        "light-cyan" => Color::LightCyan,
+        "white" => Color::White,
+        _ => return Err(format!("unknown color '{}'", value)),
+    };
+    Ok(color)
+}
+
+/// Every available theme and which one is in use.
+pub struct ThemeSet {
+    themes: Vec<Theme>,
+    current: usize,
+}
+
+impl ThemeSet {
+    /// Loads the built-in themes, then `$XDG_CONFIG_HOME/linuxtoolbox/themes/*.toml`. A user theme
+    /// with the same name as a built-in one replaces it. Selects the theme saved last time.
+    pub fn load() -> Self {
+        let mut themes: Vec<Theme> = Vec::new();
+        let mut add = |theme: Theme| match themes.iter_mut().find(|t| t.name.eq_ignore_ascii_case(&theme.name)) {
+            Some(existing) => *existing = theme,
+            None => themes.push(theme),
+        };
+
+        let mut builtin: Vec<_> = BUILTIN_THEMES.files().collect();
+        builtin.sort_by_key(|file| file.path());
+        for file in builtin {
+            let name = file.path().file_name().and_then(|n| n.to_str()).unwrap_or_default();
+            match Theme::from_toml(name, file.contents_utf8().unwrap_or_default()) {
+                Ok(theme) => add(theme),
+                Err(e) => logging::error("Invalid built-in theme", json!({ "file": name, "error": e })),
+            }
+        }
+        for theme in load_user_themes(&themes_dir()) {
+            add(theme);
+        }
+
+        let mut set = ThemeSet { themes, current: 0 };
+        if let Some(name) = load_selected() {
+            set.select(&name);
+        }
+        set
+    }
+
+    pub fn current(&self) -> &Theme {
+        &self.themes[self.current]
+    }
+
+    pub fn themes(&self) -> &[Theme] {
+        &self.themes
+    }
+
+    pub fn current_index(&self) -> usize {
+        self.current
+    }
+
+    pub fn set_current(&mut self, index: usize) {
+        self.current = index.min(self.themes.len() - 1);
+    }
+
+    pub fn next(&mut self) {
+        self.current = (self.current + 1) % self.themes.len();
+    }
+
+    /// Switches to the theme with this name or id. Returns false if there is none.
+    pub fn select(&mut self, name: &str) -> bool {
+        match self.themes.iter().position(|theme| theme.matches(name)) {
+            Some(index) => {
+                self.current = index;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Remembers the current theme for the next start.
+    pub fn save(&self) -> std::io::Result<()> {
+        let dir = config::user_config_dir();
+        fs::create_dir_all(&dir)?;
+        let selected = SelectedTheme { theme: self.current().name.clone() };
+        fs::write(dir.join(SELECTED_THEME_FILE), serde_json::to_string(&selected)?)
+    }
+}
+
+pub fn themes_dir() -> std::path::PathBuf {
+    config::user_config_dir().join("themes")
+}
+
+fn load_user_themes(dir: &Path) -> Vec<Theme> {
+    let Ok(entries) = fs::read_dir(dir) else { return Vec::new() };
+    let mut paths: Vec<_> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .collect();
+    paths.sort();
+
+    paths
+        .iter()
+        .filter_map(|path| {
+            let name = path.file_name()?.to_str()?;
+            let result = fs::read_to_string(path).map_err(|e| e.to_string()).and_then(|contents| Theme::from_toml(name, &contents));
+            result
+                .map_err(|e| logging::warn("Skipping invalid theme", json!({ "path": path, "error": e })))
+                .ok()
+        })
+        .collect()
+}
+
+#[derive(Serialize, Deserialize)]
+struct SelectedTheme {
+    theme: String,
+}
+
+fn load_selected() -> Option<String> {
+    let contents = fs::read_to_string(config::user_config_dir().join(SELECTED_THEME_FILE)).ok()?;
+    // Older versions stored the bare enum variant, e.g. "Ocean" or "Tokyo"
+    serde_json::from_str::<SelectedTheme>(&contents)
+        .map(|selected| selected.theme)
+        .or_else(|_| serde_json::from_str::<String>(&contents))
+        .ok()
+}
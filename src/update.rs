@@ -0,0 +1,331 @@
+use std::fs;
+use std::io::Write;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+use chrono::{DateTime, Local};
+use reqwest::blocking::Client;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::settings::UpdateSettings;
+use crate::{embedded, integrity, logging, state, CURRENT_VERSION};
+
+/// Set to any non-empty value to skip the update check, like `--offline`
+pub const OFFLINE_ENV: &str = "LINUXTOOLBOX_OFFLINE";
+/// Override `update.repo` and `update.api_url` from settings.toml, e.g. to test against a local stub
+pub const REPO_ENV: &str = "LINUXTOOLBOX_UPDATE_REPO";
+pub const API_URL_ENV: &str = "LINUXTOOLBOX_UPDATE_API";
+const CACHE_FILE: &str = "update-check.json";
+/// The check runs while the menu is already usable, but an answer after this long is not worth waiting for
+const CHECK_TIMEOUT_SECS: u64 = 5;
+/// Downloads are a few megabytes; give slow links time but never hang forever
+const DOWNLOAD_TIMEOUT_SECS: u64 = 300;
+const ELF_MAGIC: &[u8] = b"\x7fELF";
+
+/// Where releases come from and whether to look for them unprompted.
+#[derive(Clone)]
+pub struct UpdateSource {
+    pub check_enabled: bool,
+    /// Set by `--offline` or $LINUXTOOLBOX_OFFLINE; also stops explicit `update`
+    pub offline: bool,
+    pub repo: String,
+    pub api_url: String,
+    pub cache_ttl: chrono::Duration,
+}
+
+impl UpdateSource {
+    pub fn new(settings: &UpdateSettings, offline: bool) -> Self {
+        let env = |name| std::env::var(name).ok().filter(|value: &String| !value.is_empty());
+        let offline = offline || env(OFFLINE_ENV).is_some();
+        UpdateSource {
+            check_enabled: settings.check && !offline,
+            offline,
+            repo: env(REPO_ENV).unwrap_or_else(|| settings.repo.clone()),
+            api_url: env(API_URL_ENV).unwrap_or_else(|| settings.api_url.clone()).trim_end_matches('/').to_string(),
+            cache_ttl: chrono::Duration::hours(settings.cache_hours as i64),
+        }
+    }
+
+    fn latest_release_url(&self) -> String {
+        format!("{}/repos/{}/releases/latest", self.api_url, self.repo)
+    }
+}
+
+/// Last check result, reused until it is older than the cache TTL.
+#[derive(Serialize, Deserialize)]
+struct CachedCheck {
+    checked_at: DateTime<Local>,
+    /// Version and repo the check was made for; a different binary or fork asks again
+    current_version: String,
+    repo: String,
+    release: Option<Release>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Release {
+    pub tag_name: String,
+    #[serde(default)]
+    pub assets: Vec<Asset>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Asset {
+    pub name: String,
+    pub browser_download_url: String,
+}
+
+impl Release {
+    pub fn version(&self) -> Option<Version> {
+        parse_version(&self.tag_name)
+    }
+
+    /// The binary built for this machine: a plain (not archived) asset naming our architecture,
+    /// or one simply called `linuxtoolbox`.
+    pub fn binary_asset(&self) -> Option<&Asset> {
+        let arch_names: &[&str] = match std::env::consts::ARCH {
+            "x86_64" => &["x86_64", "amd64"],
+            "aarch64" => &["aarch64", "arm64"],
+            other => return self.assets.iter().find(|a| a.name.contains(other) && is_binary_name(&a.name)),
+        };
+        self.assets
+            .iter()
+            .filter(|a| is_binary_name(&a.name))
+            .find(|a| arch_names.iter().any(|arch| a.name.contains(arch)))
+            .or_else(|| self.assets.iter().find(|a| a.name == "linuxtoolbox"))
+    }
+
+    /// Expected sha256 of an asset, from `<asset>.sha256` or a SHA256SUMS-style file.
+    fn published_checksum(&self, client: &Client, asset: &Asset) -> Result<Option<String>, Box<dyn std::error::Error>> {
+        let sidecar = format!("{}.sha256", asset.name);
+        let sums = self.assets.iter().find(|a| a.name == sidecar).or_else(|| {
+            self.assets.iter().find(|a| matches!(a.name.to_ascii_lowercase().as_str(), "sha256sums" | "sha256sums.txt" | "checksums.txt"))
+        });
+        let Some(sums) = sums else { return Ok(None) };
+
+        let text = client.get(&sums.browser_download_url).header("User-Agent", "LinuxToolbox").send()?.error_for_status()?.text()?;
+        // Either a bare hash, or "<hash>  <file name>" lines
+        for line in text.lines() {
+            let mut parts = line.split_whitespace();
+            let (Some(hash), name) = (parts.next(), parts.next()) else { continue };
+            if name.is_none_or(|name| name.trim_start_matches('*') == asset.name) {
+                return Ok(Some(hash.to_ascii_lowercase()));
+            }
+        }
+        Err(format!("{} does not list {}", sums.name, asset.name).into())
+    }
+}
+
+fn is_binary_name(name: &str) -> bool {
+    let lower = name.to_ascii_lowercase();
+    ![".tar.gz", ".tgz", ".zip", ".sha256", ".txt", ".sig", ".asc", ".deb", ".rpm"].iter().any(|ext| lower.ends_with(ext))
+}
+
+/// Parses release tags leniently: `v0.6` is 0.6.0 and `v0.05` is 0.5.0, neither of which is valid semver.
+pub fn parse_version(tag: &str) -> Option<Version> {
+    let version = tag.trim().trim_start_matches(['v', 'V']);
+    if let Ok(parsed) = Version::parse(version) {
+        return Some(parsed);
+    }
+    let (core, pre) = version.split_once('-').map_or((version, None), |(core, pre)| (core, Some(pre)));
+    let mut numbers = core.split('.').map(|part| part.parse::<u64>());
+    let major = numbers.next()?.ok()?;
+    let minor = numbers.next().unwrap_or(Ok(0)).ok()?;
+    let patch = numbers.next().unwrap_or(Ok(0)).ok()?;
+    if numbers.next().is_some() {
+        return None;
+    }
+    let mut parsed = Version::new(major, minor, patch);
+    if let Some(pre) = pre {
+        parsed.pre = semver::Prerelease::new(pre).ok()?;
+    }
+    Some(parsed)
+}
+
+fn check_client() -> reqwest::Result<Client> {
+    Client::builder()
+        .timeout(Duration::from_secs(CHECK_TIMEOUT_SECS))
+        .connect_timeout(Duration::from_secs(CHECK_TIMEOUT_SECS))
+        .build()
+}
+
+pub fn fetch_latest_release(source: &UpdateSource) -> Result<Release, Box<dyn std::error::Error>> {
+    if source.offline {
+        return Err("Offline mode is on (--offline or $LINUXTOOLBOX_OFFLINE)".into());
+    }
+    let url = source.latest_release_url();
+    logging::debug("Checking for updates", json!({ "current_version": CURRENT_VERSION, "url": url }));
+    let release: Release = check_client()?.get(&url).header("User-Agent", "LinuxToolbox").send()?.error_for_status()?.json()?;
+    logging::debug("Latest release found", json!({ "tag": release.tag_name }));
+    Ok(release)
+}
+
+/// The latest release if it is newer than this binary. Always asks the server.
+pub fn newer_release(source: &UpdateSource) -> Result<Option<Release>, Box<dyn std::error::Error>> {
+    let current = Version::parse(CURRENT_VERSION)?;
+    let release = fetch_latest_release(source)?;
+    let latest = release
+        .version()
+        .ok_or_else(|| format!("Failed to parse latest version '{}'", release.tag_name))?;
+    let newer = (latest > current).then_some(release);
+    save_cache(source, &newer);
+    Ok(newer)
+}
+
+/// Like `newer_release`, but answers from the cache while it is fresh.
+fn cached_newer_release(source: &UpdateSource) -> Result<Option<Release>, Box<dyn std::error::Error>> {
+    let cached = std::fs::read_to_string(state::state_dir().join(CACHE_FILE))
+        .ok()
+        .and_then(|contents| serde_json::from_str::<CachedCheck>(&contents).ok())
+        .filter(|cache| cache.current_version == CURRENT_VERSION && cache.repo == source.repo)
+        .filter(|cache| Local::now() - cache.checked_at < source.cache_ttl);
+    if let Some(cache) = cached {
+        logging::debug("Using cached update check", json!({ "checked_at": cache.checked_at.to_rfc3339() }));
+        return Ok(cache.release);
+    }
+    newer_release(source)
+}
+
+fn save_cache(source: &UpdateSource, release: &Option<Release>) {
+    let cache = CachedCheck {
+        checked_at: Local::now(),
+        current_version: CURRENT_VERSION.to_string(),
+        repo: source.repo.clone(),
+        release: release.clone(),
+    };
+    let path = state::state_dir().join(CACHE_FILE);
+    let result = std::fs::create_dir_all(state::state_dir())
+        .and_then(|_| std::fs::write(&path, serde_json::to_string(&cache).unwrap_or_default()));
+    if let Err(e) = result {
+        logging::warn("Failed to cache update check", json!({ "path": path, "error": e.to_string() }));
+    }
+}
+
+/// Runs the startup check on its own thread so a slow or missing network never holds up the menu.
+/// The receiver yields one value, the newer release if there is one; failures are logged.
+pub fn spawn_check(source: &UpdateSource) -> Option<Receiver<Option<Release>>> {
+    if !source.check_enabled {
+        logging::debug("Update check disabled", json!({ "offline": source.offline }));
+        return None;
+    }
+    let source = source.clone();
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        let release = cached_newer_release(&source).unwrap_or_else(|e| {
+            logging::warn("Update check failed", json!({ "error": e.to_string() }));
+            None
+        });
+        let _ = sender.send(release);
+    });
+    Some(receiver)
+}
+
+/// Downloads the release binary, checks it, and swaps it in for the running executable.
+/// `require_checksum` refuses releases that publish no sha256 for the asset.
+pub fn install(release: &Release, require_checksum: bool) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let version = release.version().ok_or_else(|| format!("Unrecognised release tag '{}'", release.tag_name))?;
+    let asset = release
+        .binary_asset()
+        .ok_or_else(|| format!("Release {} has no binary for {}", release.tag_name, std::env::consts::ARCH))?;
+    let exe = std::env::current_exe()?.canonicalize()?;
+    let dir = exe.parent().ok_or("Cannot locate the executable's directory")?;
+
+    let client = Client::builder().timeout(Duration::from_secs(DOWNLOAD_TIMEOUT_SECS)).build()?;
+    let expected = release.published_checksum(&client, asset)?;
+    if expected.is_none() && require_checksum {
+        return Err(format!("Release {} publishes no sha256 for {}; pass --skip-checksum to install it anyway", release.tag_name, asset.name).into());
+    }
+
+    logging::info("Downloading update", json!({ "tag": release.tag_name, "asset": asset.name, "url": asset.browser_download_url }));
+    let bytes = client.get(&asset.browser_download_url).header("User-Agent", "LinuxToolbox").send()?.error_for_status()?.bytes()?;
+    if !bytes.starts_with(ELF_MAGIC) {
+        return Err(format!("{} is not a Linux executable", asset.name).into());
+    }
+
+    // Staged next to the executable so the final rename stays on one filesystem and is atomic
+    let staged = dir.join(format!(".linuxtoolbox-{}.new", version));
+    let result = stage_and_replace(&bytes, &staged, &exe, expected.as_deref(), &version);
+    if result.is_err() {
+        let _ = fs::remove_file(&staged);
+    }
+    result?;
+
+    refresh_scripts();
+    logging::info("Update installed", json!({ "from": CURRENT_VERSION, "to": version.to_string(), "path": exe }));
+    Ok(exe)
+}
+
+fn stage_and_replace(bytes: &[u8], staged: &Path, exe: &Path, expected: Option<&str>, version: &Version) -> Result<(), Box<dyn std::error::Error>> {
+    let mut file = fs::File::create(staged).map_err(|e| format!("Cannot write to {}: {} (try again with sudo)", staged.display(), e))?;
+    file.write_all(bytes)?;
+    file.sync_all()?;
+    drop(file);
+    fs::set_permissions(staged, fs::Permissions::from_mode(0o755))?;
+
+    if let Some(expected) = expected {
+        let actual = integrity::sha256_file(staged)?;
+        if actual != expected {
+            return Err(format!("Checksum mismatch: expected {}, downloaded file has {}", expected, actual).into());
+        }
+    }
+
+    // A binary that cannot report its own version would leave us unable to update again
+    let output = Command::new(staged).arg("version").output()?;
+    let reported = String::from_utf8_lossy(&output.stdout);
+    if !output.status.success() || reported.split_whitespace().last().and_then(parse_version).as_ref() != Some(version) {
+        return Err(format!("Downloaded binary reports '{}', expected {}", reported.trim(), version).into());
+    }
+
+    fs::rename(staged, exe).map_err(|e| format!("Cannot replace {}: {} (try again with sudo)", exe.display(), e))?;
+    Ok(())
+}
+
+/// Drops scripts extracted by this version. The new binary extracts its own copies on first use.
+fn refresh_scripts() {
+    let cache = embedded::cache_dir();
+    if cache.exists() {
+        if let Err(e) = fs::remove_dir_all(&cache) {
+            logging::warn("Failed to remove old extracted scripts", json!({ "path": cache, "error": e.to_string() }));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_version_pads_short_tags() {
+        assert_eq!(parse_version("v0.6"), Some(Version::new(0, 6, 0)));
+        assert_eq!(parse_version("V2"), Some(Version::new(2, 0, 0)));
+        assert_eq!(parse_version(" 1.2.3 "), Some(Version::new(1, 2, 3)));
+    }
+
+    #[test]
+    fn parse_version_drops_leading_zeros() {
+        assert_eq!(parse_version("v0.05"), Some(Version::new(0, 5, 0)));
+        assert_eq!(parse_version("v0.6.07"), Some(Version::new(0, 6, 7)));
+    }
+
+    #[test]
+    fn parse_version_keeps_pre_release_tags() {
+        let strict = parse_version("v1.2.3-beta.1").unwrap();
+        assert_eq!(strict.pre.as_str(), "beta.1");
+        let lenient = parse_version("v0.6-rc1").unwrap();
+        assert_eq!((lenient.major, lenient.minor, lenient.patch), (0, 6, 0));
+        assert_eq!(lenient.pre.as_str(), "rc1");
+        assert!(lenient < Version::new(0, 6, 0));
+    }
+
+    #[test]
+    fn parse_version_rejects_other_tags() {
+        assert_eq!(parse_version("v1.2.3.4"), None);
+        assert_eq!(parse_version("latest"), None);
+        assert_eq!(parse_version("v1.x"), None);
+        assert_eq!(parse_version(""), None);
+    }
+}